@@ -0,0 +1,67 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rct::styles::color::{Colorizer, Font};
+use rct::{ICell, Table};
+use std::io;
+
+/// Builds a table of `rows` movies, the titles being colored when `colored` is set.
+fn movies(rows: usize, colored: bool) -> Table {
+    let mut table = Table::new();
+    table.add_header(vec![
+        "ID".cell(),
+        "Title".cell(),
+        "price".cell(),
+        "description".cell(),
+        "created_at".cell(),
+    ]);
+    for id in 0..rows {
+        let title = format!("Harry Potter {id}").cell();
+        table.add_row(vec![
+            id.cell(),
+            if colored {
+                title.color("#ff0000").font(Font::Bold)
+            } else {
+                title
+            },
+            format!("{:.2}", id as f32 * 0.37).cell(),
+            "Spider-man, \nNo Way Home.".cell(),
+            "2018-12-12 09:04:50".cell(),
+        ]);
+    }
+
+    table
+}
+
+fn render(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render");
+    group.sample_size(10);
+
+    for rows in [10_000, 100_000, 1_000_000] {
+        group.throughput(Throughput::Elements(rows as u64));
+        for colored in [false, true] {
+            let table = movies(rows, colored);
+            let name = if colored { "colored" } else { "plain" };
+            group.bench_with_input(BenchmarkId::new(name, rows), &table, |b, table| {
+                b.iter(|| table.write_to(&mut io::sink()).unwrap())
+            });
+        }
+    }
+
+    group.finish();
+}
+
+fn build(c: &mut Criterion) {
+    let mut group = c.benchmark_group("build");
+    group.sample_size(10);
+
+    for rows in [10_000, 100_000, 1_000_000] {
+        group.throughput(Throughput::Elements(rows as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(rows), &rows, |b, rows| {
+            b.iter(|| movies(*rows, true))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, render, build);
+criterion_main!(benches);
@@ -1,8 +1,9 @@
+use rct::styles::borders::BorderStyle;
 use rct::ICell;
 use rct::Table;
-use rct::styles::borders::BorderStyle;
+use std::io;
 
-fn main() {
+fn main() -> io::Result<()> {
     let mut table = Table::default();
 
     table
@@ -43,6 +44,8 @@ fn main() {
             "2017-10-12 10:34:39".cell(),
         ]).border(BorderStyle::Empty);
 
-    table.view();
+    table.view()?;
     println!("\n");
+
+    Ok(())
 }
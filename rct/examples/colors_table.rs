@@ -1,8 +1,9 @@
 use rct::styles::color::{Colorizer, Font};
 use rct::ICell;
 use rct::Table;
+use std::io;
 
-fn main() {
+fn main() -> io::Result<()> {
     let mut table = Table::new();
 
     table
@@ -46,5 +47,7 @@ fn main() {
             "2017-10-12 10:34:39".cell(),
         ]);
 
-    table.view();
+    table.view()?;
+
+    Ok(())
 }
@@ -1,4 +1,4 @@
-use rct::ToTable;
+use rct::{IntoTable, ToTable};
 
 #[derive(ToTable)]
 struct Movies<T, S> {
@@ -0,0 +1,216 @@
+//! Functions summarizing the values of a column, for footers and subtotals.
+use crate::cell::{Cell, ICell};
+use crate::row::Row;
+use crate::table::Table;
+use crate::value::Value;
+use std::fmt;
+use std::sync::Arc;
+
+/// Function summarizing texts, shared by the clones of an [`Aggregate::With`].
+pub type AggregateFn = Arc<dyn Fn(&[String]) -> String + Send + Sync>;
+
+/// Summarizes the texts of the cells of a column, colors being left out.
+///
+/// The numeric aggregates skip the values that are not numbers, and keep as many decimals
+/// as the most precise value. The cells with a typed number are summarized with it instead
+/// of their text, and then the summary is a typed cell too.
+#[derive(Clone)]
+pub enum Aggregate {
+    /// Sum of the numbers.
+    Sum,
+    /// Number of values that are not empty.
+    Count,
+    /// Smallest number, displayed as it is in its cell.
+    Min,
+    /// Largest number, displayed as it is in its cell.
+    Max,
+    /// Mean of the numbers, with at least two decimals.
+    Average,
+    /// Summary computed by a function, which may capture its state, see [`Aggregate::with`].
+    With(AggregateFn),
+}
+
+impl fmt::Debug for Aggregate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Aggregate::Sum => f.write_str("Sum"),
+            Aggregate::Count => f.write_str("Count"),
+            Aggregate::Min => f.write_str("Min"),
+            Aggregate::Max => f.write_str("Max"),
+            Aggregate::Average => f.write_str("Average"),
+            Aggregate::With(_) => f.write_str("With(..)"),
+        }
+    }
+}
+
+impl Aggregate {
+    /// Summarize the texts with a function or a closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::aggregate::Aggregate;
+    ///
+    /// let unit = " €";
+    /// let total = Aggregate::with(move |values| format!("{}{unit}", values.len()));
+    /// assert_eq!(total.apply(&["1".to_string(), "2".to_string()]), "2 €");
+    /// ```
+    pub fn with<F>(summarize: F) -> Aggregate
+    where
+        F: Fn(&[String]) -> String + Send + Sync + 'static,
+    {
+        Aggregate::With(Arc::new(summarize))
+    }
+
+    /// Returns the summary of `values`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::aggregate::Aggregate;
+    ///
+    /// let values = ["14.87".to_string(), "18.8".to_string(), "n/a".to_string()];
+    /// assert_eq!(Aggregate::Sum.apply(&values), "33.67");
+    /// assert_eq!(Aggregate::Count.apply(&values), "3");
+    /// assert_eq!(Aggregate::Max.apply(&values), "18.8");
+    /// ```
+    pub fn apply(&self, values: &[String]) -> String {
+        let cells = values.iter().map(ICell::cell).collect::<Vec<_>>();
+        self.apply_cells(&cells.iter().collect::<Vec<_>>())
+            .plain_text()
+    }
+
+    /// Returns the summary of the cells, typed when it summarizes typed numbers.
+    fn apply_cells(&self, cells: &[&Cell]) -> Cell {
+        // the number of each cell, the text giving its decimals, and its typed value
+        let numbers = cells
+            .iter()
+            .filter_map(|cell| match cell.value() {
+                Some(value) => Some((value.as_f64()?, value.to_string(), Some(value), cell)),
+                None => {
+                    let text = cell.plain_text().trim().to_string();
+                    Some((text.parse::<f64>().ok()?, text, None, cell))
+                }
+            })
+            .collect::<Vec<_>>();
+        let decimals = numbers
+            .iter()
+            .map(|(_, text, ..)| {
+                text.split_once('.')
+                    .map_or(0, |(_, decimals)| decimals.len())
+            })
+            .max()
+            .unwrap_or(0);
+        let typed = numbers.iter().any(|(_, _, value, _)| value.is_some());
+        let sum = || numbers.iter().map(|(number, ..)| number).sum::<f64>();
+        let float = |text: String| match text.parse::<f64>() {
+            Ok(value) if typed => text.cell().with_value(value),
+            _ => text.cell(),
+        };
+        // the smallest or largest number, displayed as it is in its cell
+        let extreme = |number: Option<&(f64, String, Option<&Value>, &&Cell)>| match number {
+            Some((_, _, value, cell)) => {
+                let text = cell.plain_text().trim().to_string().cell();
+                match value {
+                    Some(value) => text.with_value((*value).clone()),
+                    None => text,
+                }
+            }
+            None => Cell::from_lines(vec![]),
+        };
+
+        match self {
+            Aggregate::Sum => {
+                let integers = numbers
+                    .iter()
+                    .map(|(_, _, value, _)| match value {
+                        Some(Value::Int(value)) => Some(*value),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .filter(|_| typed);
+                match integers.and_then(|integers| {
+                    integers
+                        .iter()
+                        .try_fold(0i64, |sum, value| sum.checked_add(*value))
+                }) {
+                    Some(sum) => Cell::typed(sum),
+                    None => float(format!("{:.*}", decimals, sum())),
+                }
+            }
+            Aggregate::Count => Cell::typed(
+                cells
+                    .iter()
+                    .filter(|cell| !cell.plain_text().trim().is_empty())
+                    .count(),
+            ),
+            Aggregate::Min => extreme(numbers.iter().min_by(|(a, ..), (b, ..)| a.total_cmp(b))),
+            Aggregate::Max => extreme(numbers.iter().max_by(|(a, ..), (b, ..)| a.total_cmp(b))),
+            Aggregate::Average if numbers.is_empty() => Cell::from_lines(vec![]),
+            Aggregate::Average => float(format!(
+                "{:.*}",
+                decimals.max(2),
+                sum() / numbers.len() as f64
+            )),
+            Aggregate::With(summarize) => {
+                let values = cells
+                    .iter()
+                    .map(|cell| cell.plain_text())
+                    .collect::<Vec<_>>();
+                summarize(&values).cell()
+            }
+        }
+    }
+}
+
+impl Table {
+    /// Returns a row summarizing the rows of the table, with the aggregate of every
+    /// `(column, aggregate)` pair and empty cells in the other columns. The subtotal rows
+    /// of [`Table::group_by`] are left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::aggregate::Aggregate;
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .add_header(vec!["Title".cell(), "Price".cell()])
+    ///     .add_row(vec!["Harry Potter".cell(), "14.87".cell()])
+    ///     .add_row(vec!["Avenger".cell(), "18.50".cell()]);
+    ///
+    /// let total = table.summary(&[(0, Aggregate::Count), (1, Aggregate::Sum)]);
+    /// assert_eq!(total.cells[0].to_string(), "2");
+    /// assert_eq!(total.cells[1].to_string(), "33.37");
+    /// ```
+    pub fn summary(&self, aggregates: &[(usize, Aggregate)]) -> Row {
+        summarize(
+            &self.data_rows().collect::<Vec<_>>(),
+            self.columns(),
+            aggregates,
+        )
+    }
+}
+
+/// Returns a row with the aggregates of `rows`, `columns` cells wide.
+pub(crate) fn summarize(rows: &[&Row], columns: usize, aggregates: &[(usize, Aggregate)]) -> Row {
+    let width = aggregates
+        .iter()
+        .map(|(column, _)| column + 1)
+        .max()
+        .unwrap_or(0)
+        .max(columns);
+    let mut cells = vec![Cell::from_lines(vec![]); width];
+    let empty = Cell::from_lines(vec![]);
+    for (column, aggregate) in aggregates {
+        let column_cells = rows
+            .iter()
+            .map(|row| row.cells.get(*column).unwrap_or(&empty))
+            .collect::<Vec<_>>();
+        cells[*column] = aggregate.apply_cells(&column_cells);
+    }
+
+    cells.into()
+}
@@ -0,0 +1,204 @@
+use serde_json::Value;
+use std::error::Error;
+use std::path::Path;
+
+/// Format of the records read by the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    Csv,
+    Tsv,
+    Json,
+    Ndjson,
+}
+
+impl Format {
+    /// Guesses the format from the extension of a file.
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "csv" => Some(Format::Csv),
+            "tsv" | "tab" => Some(Format::Tsv),
+            "json" => Some(Format::Json),
+            "ndjson" | "jsonl" => Some(Format::Ndjson),
+            _ => None,
+        }
+    }
+
+    /// Guesses the format from the first characters of the input.
+    /// An input starting with `{` is JSON when it holds a single value, e.g. an object
+    /// spread on several lines, and NDJSON otherwise.
+    pub fn sniff(input: &str) -> Format {
+        let trimmed = input.trim_start();
+        if trimmed.starts_with('[') {
+            Format::Json
+        } else if trimmed.starts_with('{') {
+            match serde_json::from_str::<Value>(input) {
+                Ok(_) => Format::Json,
+                Err(_) => Format::Ndjson,
+            }
+        } else if trimmed.lines().next().unwrap_or_default().contains('\t') {
+            Format::Tsv
+        } else {
+            Format::Csv
+        }
+    }
+}
+
+/// Records read from the input, before being turned into a table.
+#[derive(Debug, Default)]
+pub struct Records {
+    pub header: Option<Vec<String>>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Records {
+    /// Appends the rows of `other`, their values being moved under the columns of the same
+    /// name, and the columns missing from the header being added at its end.
+    pub fn extend(&mut self, other: Records) {
+        let (Some(header), Some(names)) = (&mut self.header, other.header.as_ref()) else {
+            if self.header.is_none() {
+                self.header = other.header;
+            }
+            self.rows.extend(other.rows);
+            return;
+        };
+        if header == names {
+            self.rows.extend(other.rows);
+            return;
+        }
+
+        // a name repeated in `other` goes to the next column of that name
+        let mut indexes: Vec<usize> = vec![];
+        for name in names {
+            let index = header
+                .iter()
+                .enumerate()
+                .position(|(index, column)| column == name && !indexes.contains(&index));
+            indexes.push(index.unwrap_or_else(|| {
+                header.push(name.clone());
+                header.len() - 1
+            }));
+        }
+        let columns = header.len();
+        for row in self.rows.iter_mut().filter(|row| row.len() < columns) {
+            row.resize(columns, String::new());
+        }
+        self.rows.extend(other.rows.into_iter().map(|row| {
+            let mut values = vec![String::new(); columns];
+            for (value, index) in row.into_iter().zip(&indexes) {
+                values[*index] = value;
+            }
+            values
+        }));
+    }
+}
+
+/// Parses `input` with the given format.
+/// `has_header` tells if the first record of a CSV or TSV input is a header.
+pub fn parse(input: &str, format: Format, has_header: bool) -> Result<Records, Box<dyn Error>> {
+    match format {
+        Format::Csv => parse_delimited(input, b',', has_header),
+        Format::Tsv => parse_delimited(input, b'\t', has_header),
+        Format::Json => {
+            // a single object is read as a single record
+            let values = match serde_json::from_str(input)? {
+                Value::Array(values) => values,
+                value => vec![value],
+            };
+            Ok(from_json(values, has_header))
+        }
+        Format::Ndjson => {
+            let values = input
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<Result<Vec<Value>, _>>()?;
+            Ok(from_json(values, has_header))
+        }
+    }
+}
+
+fn parse_delimited(
+    input: &str,
+    delimiter: u8,
+    has_header: bool,
+) -> Result<Records, Box<dyn Error>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .has_headers(has_header)
+        .flexible(true)
+        .from_reader(input.as_bytes());
+
+    let header = if has_header {
+        Some(reader.headers()?.iter().map(ToString::to_string).collect())
+    } else {
+        None
+    };
+    let rows = reader
+        .records()
+        .map(|record| Ok(record?.iter().map(ToString::to_string).collect()))
+        .collect::<Result<_, csv::Error>>()?;
+
+    Ok(Records { header, rows })
+}
+
+/// Turns JSON values in records.
+///
+/// Objects become rows whose header is the union of their keys, in the order they are met.
+/// Arrays become rows too, the first one being the header when `has_header` is set.
+fn from_json(values: Vec<Value>, has_header: bool) -> Records {
+    if values.iter().all(Value::is_array) {
+        let mut rows: Vec<Vec<String>> = values
+            .iter()
+            .map(|value| match value {
+                Value::Array(items) => items.iter().map(json_text).collect(),
+                _ => unreachable!(),
+            })
+            .collect();
+        let header = if has_header && !rows.is_empty() {
+            Some(rows.remove(0))
+        } else {
+            None
+        };
+        return Records { header, rows };
+    }
+
+    let mut keys: Vec<String> = vec![];
+    for value in &values {
+        if let Value::Object(object) = value {
+            for key in object.keys() {
+                if !keys.contains(key) {
+                    keys.push(key.clone());
+                }
+            }
+        }
+    }
+    // scalar values are displayed in a single column
+    if keys.is_empty() {
+        keys.push("value".to_string());
+    }
+
+    let rows = values
+        .iter()
+        .map(|value| match value {
+            Value::Object(object) => keys
+                .iter()
+                .map(|key| object.get(key).map(json_text).unwrap_or_default())
+                .collect(),
+            value => vec![json_text(value)],
+        })
+        .collect();
+
+    Records {
+        header: Some(keys),
+        rows,
+    }
+}
+
+/// Displays a JSON value: strings without quotes, `null` as an empty cell.
+fn json_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(string) => string.clone(),
+        value => value.to_string(),
+    }
+}
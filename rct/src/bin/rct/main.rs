@@ -0,0 +1,337 @@
+//! `rct` command line: reads CSV, TSV, JSON or NDJSON records and prints them as a table.
+//!
+//! ```text
+//! $ cat movies.csv | rct --border simple --columns id,title --align price=right
+//! ```
+
+mod input;
+
+use clap::{Parser, ValueEnum};
+use input::{Format, Records};
+use rct::styles::align::Align;
+use rct::styles::borders::BorderStyle;
+use rct::styles::color::{hex_rgb, Colorizer, Font};
+use rct::table::{self, Overflow};
+use rct::{Cell, ICell, Table};
+use std::error::Error;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "rct",
+    version,
+    about = "Pretty-prints CSV, TSV, JSON or NDJSON records as a table."
+)]
+struct Args {
+    /// Files to read, standard input is read when none is given or for `-`.
+    files: Vec<PathBuf>,
+
+    /// Format of the input, guessed from the file extension or the contents by default.
+    #[arg(short, long, value_enum)]
+    input: Option<Format>,
+
+    /// Format of the output.
+    #[arg(short, long, value_enum, default_value_t = Output::Box)]
+    output: Output,
+
+    /// Style of the borders, for the `box` output.
+    #[arg(short, long, value_enum, default_value_t = Border::Default)]
+    border: Border,
+
+    /// Layout of the rows, for the `box` output: `expanded` prints a block per record,
+    /// `auto` does it when the table is wider than the terminal, `split` prints its columns
+    /// in labelled tables as wide as the terminal.
+    #[arg(short, long, value_enum, default_value_t = Layout::Horizontal)]
+    layout: Layout,
+
+    /// Columns repeated first in every table of the `split` layout, by header name or
+    /// 1-based position (e.g. `id`).
+    #[arg(long, value_delimiter = ',')]
+    freeze: Vec<String>,
+
+    /// Display the `box` output through `$PAGER` (`less -RS` by default) when it is taller
+    /// than the terminal.
+    #[arg(long)]
+    pager: bool,
+
+    /// The first CSV or TSV record (or JSON array) is a row, not a header.
+    #[arg(long)]
+    no_header: bool,
+
+    /// Do not display the header.
+    #[arg(long)]
+    hide_header: bool,
+
+    /// Columns to display, by header name or 1-based position (e.g. `id,title` or `1,3`).
+    #[arg(short, long, value_delimiter = ',')]
+    columns: Vec<String>,
+
+    /// Maximum width of the contents of a column.
+    #[arg(short = 'w', long)]
+    max_width: Option<usize>,
+
+    /// Truncate cells wider than `--max-width` instead of wrapping them.
+    #[arg(long, requires = "max_width")]
+    truncate: bool,
+
+    /// Alignment of every column (`right`), or of a single column (`price=right`).
+    #[arg(short, long)]
+    align: Vec<String>,
+
+    /// Hexadecimal color of the header (e.g. `#00ff00`).
+    #[arg(long, value_parser = parse_color)]
+    header_color: Option<String>,
+
+    /// Hexadecimal background color of the header.
+    #[arg(long, value_parser = parse_color)]
+    header_bg: Option<String>,
+
+    /// Display the header in bold.
+    #[arg(long)]
+    bold_header: bool,
+
+    /// Hexadecimal color of the rows.
+    #[arg(long, value_parser = parse_color)]
+    color: Option<String>,
+
+    /// Hexadecimal background color of the rows.
+    #[arg(long, value_parser = parse_color)]
+    bg: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Output {
+    Box,
+    Markdown,
+    Html,
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Border {
+    Default,
+    Simple,
+    Empty,
+}
+
+impl From<Border> for BorderStyle {
+    fn from(value: Border) -> Self {
+        match value {
+            Border::Default => BorderStyle::Default,
+            Border::Simple => BorderStyle::Simple,
+            Border::Empty => BorderStyle::Empty,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Layout {
+    Horizontal,
+    Expanded,
+    Auto,
+    Split,
+}
+
+impl From<Layout> for table::Layout {
+    fn from(value: Layout) -> Self {
+        match value {
+            Layout::Horizontal => table::Layout::Horizontal,
+            Layout::Expanded => table::Layout::Expanded,
+            Layout::Auto => table::Layout::auto(),
+            Layout::Split => table::Layout::split(),
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("rct: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    let records = read_records(args)?;
+    let records = select_columns(records, &args.columns)?;
+    let table = build_table(args, &records)?;
+
+    let mut stdout = BufWriter::new(io::stdout().lock());
+    let result = match args.output {
+        Output::Box if args.pager => table.view_paged(),
+        Output::Box => table.write_to(&mut stdout),
+        Output::Markdown => stdout.write_all(table.to_markdown().as_bytes()),
+        Output::Html => stdout.write_all(table.to_html().as_bytes()),
+        Output::Csv => stdout.write_all(table.to_csv().as_bytes()),
+    };
+    match result.and_then(|_| stdout.flush()) {
+        // the reader of a pipeline (e.g. `head`) may stop before the end of the table
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => Ok(result?),
+    }
+}
+
+/// Reads and parses every input, standard input being read when no file is given.
+fn read_records(args: &Args) -> Result<Records, Box<dyn Error>> {
+    let stdin = [PathBuf::from("-")];
+    let files = if args.files.is_empty() {
+        &stdin[..]
+    } else {
+        &args.files[..]
+    };
+
+    let mut records = Records::default();
+    for path in files {
+        let mut contents = String::new();
+        if path.as_os_str() == "-" {
+            io::stdin().read_to_string(&mut contents)?;
+        } else {
+            contents = std::fs::read_to_string(path)
+                .map_err(|err| format!("{}: {err}", path.display()))?;
+        }
+
+        let format = args
+            .input
+            .or_else(|| Format::from_path(path))
+            .unwrap_or_else(|| Format::sniff(&contents));
+        let parsed = input::parse(&contents, format, !args.no_header)
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        records.extend(parsed);
+    }
+
+    Ok(records)
+}
+
+/// Keeps only the columns given with `--columns`, in that order.
+fn select_columns(records: Records, columns: &[String]) -> Result<Records, Box<dyn Error>> {
+    if columns.is_empty() {
+        return Ok(records);
+    }
+
+    let indexes = columns
+        .iter()
+        .map(|column| column_index(&records, column))
+        .collect::<Result<Vec<_>, _>>()?;
+    let project = |row: &Vec<String>| -> Vec<String> {
+        indexes
+            .iter()
+            .map(|index| row.get(*index).cloned().unwrap_or_default())
+            .collect()
+    };
+
+    Ok(Records {
+        header: records.header.as_ref().map(project),
+        rows: records.rows.iter().map(project).collect(),
+    })
+}
+
+/// Finds a column by header name, or by 1-based position.
+fn column_index(records: &Records, column: &str) -> Result<usize, Box<dyn Error>> {
+    let column = column.trim();
+    if let Some(index) = records
+        .header
+        .as_ref()
+        .and_then(|header| header.iter().position(|name| name == column))
+    {
+        return Ok(index);
+    }
+
+    match column.parse::<usize>() {
+        Ok(position) if position > 0 => Ok(position - 1),
+        _ => Err(format!("unknown column `{column}`").into()),
+    }
+}
+
+fn build_table(args: &Args, records: &Records) -> Result<Table, Box<dyn Error>> {
+    let mut table = Table::new();
+    table
+        .border(args.border.into())
+        .layout(args.layout.into())
+        .chunk_label(true);
+
+    if let Some(header) = records.header.as_ref().filter(|_| !args.hide_header) {
+        let cells = header
+            .iter()
+            .map(|name| {
+                let mut cell = name.cell();
+                if let Some(color) = &args.header_color {
+                    cell = cell.color(color);
+                }
+                if let Some(bg) = &args.header_bg {
+                    cell = cell.bg(bg);
+                }
+                if args.bold_header {
+                    cell = cell.font(Font::Bold);
+                }
+                cell
+            })
+            .collect::<Vec<Cell>>();
+        table.add_header(cells);
+    }
+
+    for row in &records.rows {
+        let cells = row
+            .iter()
+            .map(|value| {
+                let mut cell = value.cell();
+                if let Some(color) = &args.color {
+                    cell = cell.color(color);
+                }
+                if let Some(bg) = &args.bg {
+                    cell = cell.bg(bg);
+                }
+                cell
+            })
+            .collect::<Vec<Cell>>();
+        table.add_row(cells);
+    }
+
+    for align in &args.align {
+        match align.rsplit_once('=') {
+            Some((column, value)) => {
+                let index = column_index(records, column)?;
+                table.align_column(index, parse_align(value)?);
+            }
+            None => {
+                table.align(parse_align(align)?);
+            }
+        }
+    }
+
+    for column in &args.freeze {
+        table.freeze_column(column_index(records, column)?);
+    }
+
+    if let Some(width) = args.max_width {
+        table.max_width(width);
+        if args.truncate {
+            table.overflow(Overflow::Truncate);
+        }
+    }
+
+    Ok(table)
+}
+
+fn parse_align(value: &str) -> Result<Align, Box<dyn Error>> {
+    match value.trim().to_lowercase().as_str() {
+        "left" => Ok(Align::Left),
+        "center" => Ok(Align::Center),
+        "right" => Ok(Align::Right),
+        _ => Err(format!("invalid alignment `{value}`, expected left, center or right").into()),
+    }
+}
+
+fn parse_color(value: &str) -> Result<String, String> {
+    match hex_rgb(value) {
+        Some(_) => Ok(value.to_string()),
+        None => Err(format!(
+            "invalid color `{value}`, expected a hexadecimal color like `#00ff00`"
+        )),
+    }
+}
@@ -1,11 +1,21 @@
+use crate::format::{DurationFormat, TimeFormat};
+use crate::styles::color::split_colors;
+use crate::text;
+use crate::value::Value;
 use std::fmt::{Display, Formatter};
-use unicode_width::UnicodeWidthStr;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::time::{Duration, SystemTime};
 
 #[derive(Debug, Clone)]
 pub struct Cell {
     pub data: Vec<String>,
     pub height: usize,
     pub width: usize,
+    /// Display width of every line of `data`, computed once when the cell is built, with
+    /// a fingerprint of the line it was computed for.
+    pub(crate) line_widths: Vec<(u64, usize)>,
+    /// Value displayed by the cell, when it was built from a typed value.
+    pub(crate) value: Option<Value>,
 }
 
 pub trait ICell {
@@ -25,10 +35,194 @@ where
     /// This will change each values contained the display Trait in string value
     fn cell(self) -> Cell {
         let data: Vec<String> = self.to_string().lines().map(ToString::to_string).collect();
+        Cell::from_lines(data)
+    }
+}
+
+impl Cell {
+    /// Builds a cell displaying a typed value, kept for the exports and the operations
+    /// on the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::Cell;
+    /// use rct::value::Value;
+    ///
+    /// let cell = Cell::typed(18.5);
+    /// assert_eq!(cell.to_string(), "18.5");
+    /// assert_eq!(cell.value(), Some(&Value::Float(18.5)));
+    /// ```
+    pub fn typed<V: Into<Value>>(value: V) -> Cell {
+        let value = value.into();
+        value.to_string().cell().with_value(value)
+    }
+
+    /// Builds a cell displaying a duration with its two largest units, like `2h 13m`,
+    /// and keeping it for the sorts. See [`DurationFormat`] for the other displays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::Cell;
+    /// use std::time::Duration;
+    ///
+    /// assert_eq!(Cell::duration(Duration::from_secs(7_985)).to_string(), "2h 13m");
+    /// ```
+    pub fn duration(duration: Duration) -> Cell {
+        DurationFormat::humanized().cell(duration)
+    }
+
+    /// Builds a cell displaying a date-time in RFC 3339 format, in UTC, and keeping it for
+    /// the sorts. See [`TimeFormat`] for the other displays.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::Cell;
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// let time = UNIX_EPOCH + Duration::from_secs(1_007_589_920);
+    /// assert_eq!(Cell::time(time).to_string(), "2001-12-05T22:05:20Z");
+    /// ```
+    pub fn time(time: SystemTime) -> Cell {
+        TimeFormat::rfc3339().cell(time)
+    }
+
+    /// Returns the cell keeping `value` as the value it displays, e.g. for a formatted number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::value::Value;
+    ///
+    /// let cell = "14.87 €".cell().with_value(14.87);
+    /// assert_eq!(cell.to_string(), "14.87 €");
+    /// assert_eq!(cell.value(), Some(&Value::Float(14.87)));
+    /// ```
+    pub fn with_value<V: Into<Value>>(&self, value: V) -> Cell {
+        Cell {
+            value: Some(value.into()),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the typed value displayed by the cell, if it was given one.
+    pub fn value(&self) -> Option<&Value> {
+        self.value.as_ref()
+    }
+
+    /// Wraps every line of the cell so that none is wider than `width` columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    ///
+    /// let cell = "Spider-man, No Way Home.".cell().wrap(12);
+    /// assert_eq!(cell.data, vec!["Spider-man,", "No Way Home."]);
+    /// ```
+    pub fn wrap(&self, width: usize) -> Cell {
+        let data: Vec<String> = self
+            .data
+            .iter()
+            .flat_map(|line| text::wrap(line, width))
+            .collect();
+        Cell {
+            value: self.value.clone(),
+            ..Cell::from_lines(data)
+        }
+    }
+
+    /// Cuts every line of the cell wider than `width` columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    ///
+    /// let cell = "Harry Potter".cell().truncate(6);
+    /// assert_eq!(cell.data, vec!["Harry…"]);
+    /// ```
+    pub fn truncate(&self, width: usize) -> Cell {
+        let data: Vec<String> = self
+            .data
+            .iter()
+            .map(|line| text::truncate(line, width))
+            .collect();
+        Cell {
+            value: self.value.clone(),
+            ..Cell::from_lines(data)
+        }
+    }
+
+    /// Builds a cell from its lines, measuring them once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::Cell;
+    ///
+    /// let cell = Cell::from_lines(vec!["Harry".to_string(), "Potter".to_string()]);
+    /// assert_eq!((cell.width, cell.height), (6, 2));
+    /// ```
+    pub fn from_lines(data: Vec<String>) -> Cell {
+        let line_widths: Vec<(u64, usize)> = data
+            .iter()
+            .map(|line| (fingerprint(line), text::display_width(line)))
+            .collect();
+        let width = line_widths.iter().map(|(_, width)| *width).max();
         Cell {
-            data: data.clone(),
             height: data.len(),
-            width: data.iter().map(|string| string.width()).max().unwrap(),
+            width: width.unwrap_or(0),
+            line_widths,
+            data,
+            value: None,
         }
     }
+
+    /// Returns a cell with styled `data` and the same size, styles taking no room on screen.
+    pub(crate) fn with_data(&self, data: Vec<String>) -> Cell {
+        let line_widths = data
+            .iter()
+            .zip(&self.line_widths)
+            .map(|(line, (_, width))| (fingerprint(line), *width))
+            .collect();
+        Cell {
+            data,
+            height: self.height,
+            width: self.width,
+            line_widths,
+            value: self.value.clone(),
+        }
+    }
+
+    /// Returns the contents of the cell without colors.
+    pub(crate) fn plain_text(&self) -> String {
+        self.data
+            .iter()
+            .map(|line| split_colors(line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns the display width of the line at `index`.
+    pub(crate) fn line_width(&self, index: usize) -> usize {
+        let Some(line) = self.data.get(index) else {
+            return 0;
+        };
+        match self.line_widths.get(index) {
+            Some((key, width)) if *key == fingerprint(line) => *width,
+            // the line was changed in `data` after the cell was built
+            _ => text::display_width(line),
+        }
+    }
+}
+
+/// Returns a hash of a line, telling whether it is still the line that was measured.
+fn fingerprint(line: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    line.hash(&mut hasher);
+    hasher.finish()
 }
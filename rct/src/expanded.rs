@@ -0,0 +1,91 @@
+use crate::cell::{Cell, ICell};
+use crate::row::Row;
+use crate::styles::align::Align;
+use crate::table::{repeat, Table};
+use std::fmt;
+
+impl Table {
+    /// print every row of the table as a block of `header │ value` lines, below a separator
+    /// holding the number of the record.
+    /// ╔═[ RECORD 1 ]═════════╗
+    /// ║ ID    │ 1            ║
+    /// ║ Title │ Harry Potter ║
+    /// ╟─[ RECORD 2 ]─────────╢
+    /// ║ ID    │ 2            ║
+    /// ║ Title │ Avenger      ║
+    /// ╚═══════╧══════════════╝
+    pub(crate) fn print_expanded<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        let border = &self.border;
+        let columns = self.columns();
+        let keys = (0..columns)
+            .map(|index| {
+                self.header
+                    .as_ref()
+                    .and_then(|header| header.cells.get(index).cloned())
+                    .unwrap_or_else(|| index.cell())
+            })
+            .collect::<Vec<Cell>>();
+        // the footer is the last block
+        let rows = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| (format!("[ RECORD {} ]", index + 1), self.fit(row)))
+            .chain(
+                self.footer
+                    .iter()
+                    .map(|footer| ("[ FOOTER ]".to_string(), self.fit(footer))),
+            )
+            .collect::<Vec<_>>();
+
+        let key_len = keys.iter().map(|key| key.width).max().unwrap_or(0) + 2;
+        let mut value_len = rows
+            .iter()
+            .flat_map(|(_, row)| row.cells.iter().map(|cell| cell.width))
+            .max()
+            .unwrap_or(0)
+            + 2;
+        // the separators and the title are as wide as the two columns
+        let label_len = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0) + 2;
+        let title_len = self.title.as_ref().map_or(0, |title| title.width + 2);
+        let total = label_len.max(title_len).max(key_len + 1 + value_len);
+        value_len = total - key_len - 1;
+        let width_column = [key_len, value_len];
+
+        if let Some(title) = &self.title {
+            let top = [border.top_left, border.top, border.top, border.top_right];
+            self.print_group_border(f, &width_column, &[], top, border.top)?;
+            self.print_cells(f, &Row::from(vec![title.clone()]), &[total], |_| {
+                Align::Center
+            })?;
+        }
+
+        for (number, (label, row)) in rows.iter().enumerate() {
+            let (left, line, right) = match number == 0 && self.title.is_none() {
+                true => (border.top_left, border.top, border.top_right),
+                false => (border.left_mid, border.mid, border.right_mid),
+            };
+            f.write_char(left)?;
+            f.write_char(line)?;
+            f.write_str(label)?;
+            repeat(f, line, total - 1 - label.len())?;
+            f.write_char(right)?;
+            f.write_char('\n')?;
+
+            for (index, key) in keys.iter().enumerate() {
+                let value = row
+                    .cells
+                    .get(index)
+                    .cloned()
+                    .unwrap_or_else(|| Cell::from_lines(vec![]));
+                let line = Row::from(vec![key.clone(), value]);
+                self.print_cells(f, &line, &width_column, |column| match column {
+                    0 => Align::Left,
+                    _ => self.column_align(index),
+                })?;
+            }
+        }
+
+        self.print_bottom(f, &width_column)
+    }
+}
@@ -0,0 +1,297 @@
+use crate::cell::Cell;
+use crate::row::Row;
+use crate::styles::align::Align;
+use crate::table::Table;
+use crate::value::Value;
+
+impl Table {
+    /// Returns the table as CSV, without colors and without the cells heading the sections.
+    /// The cells with a typed value are written with the value instead of their text.
+    /// The footer and the subtotal rows are left out, see [`Table::add_footer`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .add_header(vec!["ID".cell(), "Title".cell()])
+    ///     .add_row(vec![1.cell(), "Spider-man, No Way Home.".cell()])
+    ///     .add_row(vec!["#2".cell().with_value(2), "Avenger".cell()]);
+    ///
+    /// assert_eq!(
+    ///     table.to_csv(),
+    ///     "ID,Title\n1,\"Spider-man, No Way Home.\"\n2,Avenger\n"
+    /// );
+    /// ```
+    pub fn to_csv(&self) -> String {
+        self.to_delimited(',')
+    }
+
+    /// Returns the table as TSV, like [`Table::to_csv`].
+    pub fn to_tsv(&self) -> String {
+        self.to_delimited('\t')
+    }
+
+    fn to_delimited(&self, delimiter: char) -> String {
+        let mut view = String::new();
+        for row in self.header.iter().chain(self.data_rows()) {
+            let fields: Vec<String> = row
+                .cells
+                .iter()
+                .map(|cell| {
+                    let text = match cell.value() {
+                        Some(value) => value.to_string(),
+                        None => cell.plain_text(),
+                    };
+                    if text.contains([delimiter, '"', '\n', '\r']) {
+                        format!("\"{}\"", text.replace('"', "\"\""))
+                    } else {
+                        text
+                    }
+                })
+                .collect();
+            view += &fields.join(&delimiter.to_string());
+            view += "\n";
+        }
+
+        view
+    }
+
+    /// Returns the rows of the table as a JSON array, without colors. Each row is an object
+    /// keyed by the texts of the header, or an array when the table has no header; the
+    /// columns without a header cell are keyed by their index. The typed values are written
+    /// as JSON values, date-times as RFC 3339 strings and durations as numbers of seconds,
+    /// and the other cells as strings.
+    /// The cells heading the sections are left out, and so are the footer and the subtotal
+    /// rows, see [`Table::add_footer`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::{Cell, ICell};
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .add_header(vec!["Title".cell(), "Price".cell(), "Stock".cell()])
+    ///     .add_row(vec!["Harry Potter".cell(), Cell::typed(14.87), Cell::typed(true)])
+    ///     .add_row(vec!["Avenger".cell(), Cell::typed(None::<f64>), "n/a".cell()]);
+    ///
+    /// assert_eq!(
+    ///     table.to_json(),
+    ///     r#"[{"Title":"Harry Potter","Price":14.87,"Stock":true},{"Title":"Avenger","Price":null,"Stock":"n/a"}]"#
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        let keys = self.header.as_ref().map(|header| {
+            (0..self.columns())
+                .map(|index| match header.cells.get(index) {
+                    Some(cell) => json_string(&cell.plain_text()),
+                    None => json_string(&index.to_string()),
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let rows = self.data_rows().map(|row| {
+            let values = row.cells.iter().map(|cell| match cell.value() {
+                Some(value) => json_value(value),
+                None => json_string(&cell.plain_text()),
+            });
+            match &keys {
+                Some(keys) => {
+                    let fields = keys
+                        .iter()
+                        .zip(values)
+                        .map(|(key, value)| format!("{key}:{value}"));
+                    format!("{{{}}}", fields.collect::<Vec<_>>().join(","))
+                }
+                None => format!("[{}]", values.collect::<Vec<_>>().join(",")),
+            }
+        });
+
+        format!("[{}]", rows.collect::<Vec<_>>().join(","))
+    }
+
+    /// Returns the table as a GitHub flavored markdown table, without colors, the numbers
+    /// being displayed with the formats of their columns.
+    /// The cells heading the sections are displayed in bold in the first column. The footer
+    /// and the subtotal rows are left out, see [`Table::add_footer`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .add_header(vec!["ID".cell(), "Title".cell()])
+    ///     .add_row(vec![1.cell(), "Harry\nPotter".cell()]);
+    ///
+    /// assert_eq!(
+    ///     table.to_markdown(),
+    ///     "| ID | Title |\n| --- | --- |\n| 1 | Harry<br>Potter |\n"
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let columns = self.columns();
+        let mut view = String::new();
+
+        let markdown_row = |row: &Row| {
+            let mut line = String::from("|");
+            for index in 0..columns {
+                let text = row
+                    .cells
+                    .get(index)
+                    .map(|cell| self.format_cell(index, cell).plain_text())
+                    .unwrap_or_default();
+                line += &format!(" {} |", text.replace('|', "\\|").replace('\n', "<br>"));
+            }
+            line + "\n"
+        };
+
+        // markdown tables cannot be displayed without a header
+        let empty = Row::from(vec![]);
+        view += &markdown_row(self.header.as_ref().unwrap_or(&empty));
+        view += "|";
+        for index in 0..columns {
+            view += match self.column_align(index) {
+                Align::Left => " --- |",
+                Align::Center => " :---: |",
+                Align::Right => " ---: |",
+            };
+        }
+        view += "\n";
+        let mut sections = self.sections.iter().peekable();
+        for (index, row) in self.rows.iter().enumerate() {
+            while let Some((_, cell)) = sections.next_if(|(start, _)| *start == index) {
+                let text = format!("**{}**", cell.plain_text());
+                view += &markdown_row(&Row::from(vec![Cell::from_lines(vec![text])]));
+            }
+            if !row.subtotal {
+                view += &markdown_row(row);
+            }
+        }
+
+        view
+    }
+
+    /// Returns the table as an HTML `<table>`, without colors, the numbers being displayed
+    /// with the formats of their columns. The cells heading the sections are rows of the body,
+    /// the footer and the subtotal rows are left out, see [`Table::add_footer`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .add_header(vec!["Title".cell()])
+    ///     .add_row(vec!["Tom & Jerry".cell()]);
+    ///
+    /// assert_eq!(
+    ///     table.to_html(),
+    ///     "<table>\n<thead>\n<tr><th>Title</th></tr>\n</thead>\n<tbody>\n<tr><td>Tom &amp; Jerry</td></tr>\n</tbody>\n</table>\n"
+    /// );
+    /// ```
+    pub fn to_html(&self) -> String {
+        let html_row = |row: &Row, tag: &str| {
+            let mut line = String::from("<tr>");
+            for (index, cell) in row.cells.iter().enumerate() {
+                let style = match self.column_align(index) {
+                    Align::Left => "",
+                    Align::Center => " style=\"text-align: center\"",
+                    Align::Right => " style=\"text-align: right\"",
+                };
+                let text = self.format_cell(index, cell).plain_text();
+                let text = escape_html(&text).replace('\n', "<br>");
+                line += &format!("<{tag}{style}>{text}</{tag}>");
+            }
+            line + "</tr>\n"
+        };
+
+        let mut view = String::from("<table>\n");
+        if let Some(title) = &self.title {
+            let text = escape_html(&title.plain_text()).replace('\n', "<br>");
+            view += &format!("<caption>{text}</caption>\n");
+        }
+        if self.header.is_some() || !self.groups.is_empty() {
+            view += "<thead>\n";
+        }
+        if !self.groups.is_empty() {
+            view += "<tr>";
+            for (index, span) in self.group_spans().into_iter().enumerate() {
+                let text = self
+                    .groups
+                    .get(index)
+                    .map(|(cell, _)| escape_html(&cell.plain_text()).replace('\n', "<br>"))
+                    .unwrap_or_default();
+                match span {
+                    1 => view += &format!("<th>{text}</th>"),
+                    span => view += &format!("<th colspan=\"{span}\">{text}</th>"),
+                }
+            }
+            view += "</tr>\n";
+        }
+        if let Some(header) = &self.header {
+            view += &html_row(header, "th");
+        }
+        if self.header.is_some() || !self.groups.is_empty() {
+            view += "</thead>\n";
+        }
+        view += "<tbody>\n";
+        let mut sections = self.sections.iter().peekable();
+        for (index, row) in self.rows.iter().enumerate() {
+            while let Some((_, cell)) = sections.next_if(|(start, _)| *start == index) {
+                let text = escape_html(&cell.plain_text()).replace('\n', "<br>");
+                let columns = self.columns();
+                view += &format!("<tr><th colspan=\"{columns}\">{text}</th></tr>\n");
+            }
+            if !row.subtotal {
+                view += &html_row(row, "td");
+            }
+        }
+        view += "</tbody>\n";
+        view += "</table>\n";
+
+        view
+    }
+}
+
+fn json_value(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Float(number) if !number.is_finite() => "null".to_string(),
+        Value::DateTime(_) => json_string(&value.to_string()),
+        value => value.to_string(),
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json += "\\\"",
+            '\\' => json += "\\\\",
+            '\n' => json += "\\n",
+            '\r' => json += "\\r",
+            '\t' => json += "\\t",
+            c if c.is_control() => json += &format!("\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+
+    json + "\""
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,624 @@
+//! Formats displaying the typed values of the cells: numbers, durations and date-times.
+use crate::cell::{Cell, ICell};
+use crate::text::replace_text;
+use crate::value::{Civil, Value};
+use std::time::{Duration, SystemTime};
+
+/// How the typed values of a column are displayed, see
+/// [`Table::format_column`](crate::table::Table::format_column).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Format {
+    Number(NumberFormat),
+    Duration(DurationFormat),
+    Time(TimeFormat),
+}
+
+impl From<NumberFormat> for Format {
+    fn from(format: NumberFormat) -> Self {
+        Format::Number(format)
+    }
+}
+
+impl From<DurationFormat> for Format {
+    fn from(format: DurationFormat) -> Self {
+        Format::Duration(format)
+    }
+}
+
+impl From<TimeFormat> for Format {
+    fn from(format: TimeFormat) -> Self {
+        Format::Time(format)
+    }
+}
+
+impl Format {
+    /// Returns the cell with its typed value displayed with this format, keeping its colors
+    /// when it is on a single line. Returns `None` when the format does not apply to the value.
+    pub(crate) fn apply(&self, cell: &Cell) -> Option<Cell> {
+        let text = match self {
+            Format::Number(format) => format.format_value(cell.value()?),
+            Format::Duration(format) => format.format_value(cell.value()?),
+            Format::Time(format) => format.format_value(cell.value()?),
+        }?;
+        let data = match cell.data.as_slice() {
+            [line] => vec![replace_text(line, &text)],
+            _ => vec![text],
+        };
+
+        Some(Cell {
+            value: cell.value.clone(),
+            ..Cell::from_lines(data)
+        })
+    }
+}
+
+/// Returns a cell displaying `text`, or the value itself when the format does not apply to it.
+fn typed_cell(value: Value, text: Option<String>) -> Cell {
+    text.unwrap_or_else(|| value.to_string())
+        .cell()
+        .with_value(value)
+}
+
+/// How a number is displayed: its decimals, its separators, and its unit.
+///
+/// A format builds typed cells with [`NumberFormat::cell`], or is given to the cells of
+/// a column holding typed numbers with [`Table::format_column`](crate::table::Table::format_column),
+/// like [`DurationFormat`] and [`TimeFormat`].
+///
+/// # Examples
+///
+/// ```
+/// use rct::format::NumberFormat;
+///
+/// assert_eq!(NumberFormat::new().thousands(',').format(1234567.5), "1,234,567.5");
+/// assert_eq!(NumberFormat::currency("$").format(-1234.5), "-$1,234.50");
+/// assert_eq!(
+///     NumberFormat::currency_after("€").separators(' ', ',').format(14.87),
+///     "14,87 €"
+/// );
+/// assert_eq!(NumberFormat::percent().format(0.125), "12.5%");
+/// assert_eq!(NumberFormat::bytes().format(1536), "1.5 KiB");
+/// assert_eq!(NumberFormat::si("W").format(2_400_000), "2.4 MW");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberFormat {
+    unit: Unit,
+    decimals: Option<usize>,
+    thousands: Option<char>,
+    decimal_point: char,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Unit {
+    None,
+    Percent,
+    Currency { symbol: String, after: bool },
+    Bytes,
+    Si(String),
+}
+
+const BINARY_PREFIXES: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+const SI_PREFIXES: [(f64, &str); 12] = [
+    (1e18, "E"),
+    (1e15, "P"),
+    (1e12, "T"),
+    (1e9, "G"),
+    (1e6, "M"),
+    (1e3, "k"),
+    (1.0, ""),
+    (1e-3, "m"),
+    (1e-6, "µ"),
+    (1e-9, "n"),
+    (1e-12, "p"),
+    (1e-15, "f"),
+];
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        NumberFormat::new()
+    }
+}
+
+impl NumberFormat {
+    /// Numbers displayed as they are, without thousands separators.
+    pub fn new() -> NumberFormat {
+        NumberFormat {
+            unit: Unit::None,
+            decimals: None,
+            thousands: None,
+            decimal_point: '.',
+        }
+    }
+
+    /// Ratios displayed as percentages, `0.125` being `12.5%`, with one decimal.
+    pub fn percent() -> NumberFormat {
+        NumberFormat {
+            unit: Unit::Percent,
+            decimals: Some(1),
+            ..NumberFormat::new()
+        }
+    }
+
+    /// Amounts with `symbol` before them, two decimals and thousands separators: `$1,234.50`.
+    pub fn currency(symbol: &str) -> NumberFormat {
+        NumberFormat {
+            unit: Unit::Currency {
+                symbol: symbol.to_string(),
+                after: false,
+            },
+            decimals: Some(2),
+            thousands: Some(','),
+            ..NumberFormat::new()
+        }
+    }
+
+    /// Amounts with `symbol` after them and a space, two decimals and thousands separators:
+    /// `1,234.50 €`.
+    pub fn currency_after(symbol: &str) -> NumberFormat {
+        NumberFormat {
+            unit: Unit::Currency {
+                symbol: symbol.to_string(),
+                after: true,
+            },
+            ..NumberFormat::currency(symbol)
+        }
+    }
+
+    /// Sizes in bytes with binary prefixes, `1536` being `1.5 KiB`, with one decimal.
+    pub fn bytes() -> NumberFormat {
+        NumberFormat {
+            unit: Unit::Bytes,
+            decimals: Some(1),
+            ..NumberFormat::new()
+        }
+    }
+
+    /// Quantities of `unit` with SI prefixes, `2400` being `2.4 kW` for `W`, with one decimal.
+    pub fn si(unit: &str) -> NumberFormat {
+        NumberFormat {
+            unit: Unit::Si(unit.to_string()),
+            decimals: Some(1),
+            ..NumberFormat::new()
+        }
+    }
+
+    /// Display the numbers with `decimals` decimals. The sizes and quantities without prefix
+    /// that are integers are displayed without decimals.
+    pub fn decimals(mut self, decimals: usize) -> NumberFormat {
+        self.decimals = Some(decimals);
+
+        self
+    }
+
+    /// Separate the thousands of the integer part with `separator`.
+    pub fn thousands(mut self, separator: char) -> NumberFormat {
+        self.thousands = Some(separator);
+
+        self
+    }
+
+    /// Use the separators of a locale, e.g. `(' ', ',')` for `1 234,5`.
+    pub fn separators(mut self, thousands: char, decimal_point: char) -> NumberFormat {
+        self.thousands = Some(thousands);
+        self.decimal_point = decimal_point;
+
+        self
+    }
+
+    /// Returns the text of a value, or an empty text when it is not a number.
+    pub fn format<V: Into<Value>>(&self, value: V) -> String {
+        self.format_value(&value.into()).unwrap_or_default()
+    }
+
+    /// Returns a cell displaying a value with this format, keeping the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::format::NumberFormat;
+    /// use rct::value::Value;
+    ///
+    /// let cell = NumberFormat::currency("$").cell(18.5);
+    /// assert_eq!(cell.to_string(), "$18.50");
+    /// assert_eq!(cell.value(), Some(&Value::Float(18.5)));
+    /// ```
+    pub fn cell<V: Into<Value>>(&self, value: V) -> Cell {
+        let value = value.into();
+        typed_cell(value.clone(), self.format_value(&value))
+    }
+
+    /// Returns the text of a number, `None` when the value is not a number.
+    fn format_value(&self, value: &Value) -> Option<String> {
+        let number = value.as_f64()?;
+        if !number.is_finite() {
+            return Some(value.to_string());
+        }
+
+        let text = match &self.unit {
+            Unit::None => self.number(value, 1.0, self.decimals),
+            Unit::Percent => self.number(value, 100.0, self.decimals) + "%",
+            Unit::Currency {
+                symbol,
+                after: false,
+            } => {
+                let text = self.number(value, 1.0, self.decimals);
+                match text.strip_prefix('-') {
+                    Some(text) => format!("-{symbol}{text}"),
+                    None => format!("{symbol}{text}"),
+                }
+            }
+            Unit::Currency {
+                symbol,
+                after: true,
+            } => {
+                format!("{} {symbol}", self.number(value, 1.0, self.decimals))
+            }
+            Unit::Bytes => {
+                let mut index = 0;
+                while index + 1 < BINARY_PREFIXES.len()
+                    && self.rounded(number, 1024f64.powi(index as i32 + 1)) >= 1.0
+                {
+                    index += 1;
+                }
+                let text = match index {
+                    0 => self.number(value, 1.0, self.decimals.filter(|_| number.fract() != 0.0)),
+                    index => self.number(value, 1024f64.powi(-(index as i32)), self.decimals),
+                };
+                format!("{text} {}", BINARY_PREFIXES[index])
+            }
+            Unit::Si(unit) => {
+                let (scale, prefix) = SI_PREFIXES
+                    .iter()
+                    .find(|(scale, _)| self.rounded(number, *scale) >= 1.0)
+                    .copied()
+                    .unwrap_or((1.0, ""));
+                let text = match scale == 1.0 && number.fract() == 0.0 {
+                    true => self.number(value, 1.0, None),
+                    false => self.number(value, 1.0 / scale, self.decimals),
+                };
+                format!("{text} {prefix}{unit}")
+            }
+        };
+
+        Some(text)
+    }
+
+    /// Returns the absolute value of `number` in units of `scale`, rounded to the decimals.
+    fn rounded(&self, number: f64, scale: f64) -> f64 {
+        let text = format!("{:.*}", self.decimals.unwrap_or(15), (number / scale).abs());
+        text.parse().unwrap_or(0.0)
+    }
+
+    /// Returns the text of a value multiplied by `scale`, with the separators.
+    fn number(&self, value: &Value, scale: f64, decimals: Option<usize>) -> String {
+        let text = match (value, decimals) {
+            // integers are written without going through a float, which could round them
+            (Value::Int(value), None) if scale == 1.0 => value.to_string(),
+            (Value::Int(value), Some(0)) if scale == 1.0 => value.to_string(),
+            (Value::Int(value), Some(decimals)) if scale == 1.0 => {
+                format!("{value}.{}", "0".repeat(decimals))
+            }
+            (value, None) => (value.as_f64().unwrap_or(0.0) * scale).to_string(),
+            (value, Some(decimals)) => {
+                format!("{:.*}", decimals, value.as_f64().unwrap_or(0.0) * scale)
+            }
+        };
+
+        let (sign, text) = match text.strip_prefix('-') {
+            Some(text) => ("-", text),
+            None => ("", text.as_str()),
+        };
+        let (integer, fraction) = match text.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (text, None),
+        };
+
+        let mut grouped = String::from(sign);
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                grouped.extend(self.thousands);
+            }
+            grouped.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            grouped.push(self.decimal_point);
+            grouped.push_str(fraction);
+        }
+
+        grouped
+    }
+}
+
+/// How a duration is displayed: its largest units, `2h 13m` with the two largest by default.
+///
+/// # Examples
+///
+/// ```
+/// use rct::format::DurationFormat;
+/// use std::time::Duration;
+///
+/// let format = DurationFormat::humanized();
+/// assert_eq!(format.format(Duration::from_secs(7_985)), "2h 13m");
+/// assert_eq!(format.format(Duration::from_secs(90_061)), "1d 1h");
+/// assert_eq!(format.format(Duration::from_millis(350)), "350ms");
+/// assert_eq!(format.units(3).format(Duration::from_secs(7_985)), "2h 13m 5s");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurationFormat {
+    units: usize,
+}
+
+const DURATION_UNITS: [(u128, &str); 5] = [
+    (86_400_000, "d"),
+    (3_600_000, "h"),
+    (60_000, "m"),
+    (1_000, "s"),
+    (1, "ms"),
+];
+
+impl Default for DurationFormat {
+    fn default() -> Self {
+        DurationFormat::humanized()
+    }
+}
+
+impl DurationFormat {
+    /// Durations displayed with their two largest units, from days to milliseconds.
+    pub fn humanized() -> DurationFormat {
+        DurationFormat { units: 2 }
+    }
+
+    /// Display at most `units` units, the units equal to zero being left out.
+    pub fn units(mut self, units: usize) -> DurationFormat {
+        self.units = units.max(1);
+
+        self
+    }
+
+    /// Returns the text of a duration, or an empty text when the value is not a duration.
+    pub fn format<V: Into<Value>>(&self, value: V) -> String {
+        self.format_value(&value.into()).unwrap_or_default()
+    }
+
+    /// Returns a cell displaying a duration with this format, keeping it for the sorts.
+    pub fn cell<V: Into<Value>>(&self, value: V) -> Cell {
+        let value = value.into();
+        typed_cell(value.clone(), self.format_value(&value))
+    }
+
+    fn format_value(&self, value: &Value) -> Option<String> {
+        let Value::Duration(duration) = value else {
+            return None;
+        };
+
+        let mut rest = duration.as_millis();
+        let first = DURATION_UNITS
+            .iter()
+            .position(|(millis, _)| rest >= *millis)
+            .unwrap_or(DURATION_UNITS.len() - 1);
+        let mut parts = vec![];
+        for (millis, unit) in DURATION_UNITS.iter().skip(first).take(self.units) {
+            let count = rest / millis;
+            rest %= millis;
+            if count > 0 || (parts.is_empty() && rest == 0) {
+                parts.push(format!("{count}{unit}"));
+            }
+        }
+
+        Some(parts.join(" "))
+    }
+}
+
+/// How a date-time is displayed: in RFC 3339 format, with a pattern, or relatively to a clock.
+/// Date-times are displayed in UTC.
+///
+/// # Examples
+///
+/// ```
+/// use rct::format::TimeFormat;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let time = UNIX_EPOCH + Duration::from_secs(1_007_589_920);
+/// assert_eq!(TimeFormat::rfc3339().format(time), "2001-12-05T22:05:20Z");
+/// assert_eq!(TimeFormat::pattern("%Y-%m-%d %H:%M").format(time), "2001-12-05 22:05");
+///
+/// let now = time + Duration::from_secs(3 * 86_400);
+/// assert_eq!(TimeFormat::relative(now).format(time), "3 days ago");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeFormat {
+    kind: TimeKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TimeKind {
+    Rfc3339,
+    Pattern(String),
+    Relative(SystemTime),
+}
+
+const RELATIVE_UNITS: [(u64, &str); 6] = [
+    (365 * 86_400, "year"),
+    (30 * 86_400, "month"),
+    (86_400, "day"),
+    (3_600, "hour"),
+    (60, "minute"),
+    (1, "second"),
+];
+
+impl Default for TimeFormat {
+    fn default() -> Self {
+        TimeFormat::rfc3339()
+    }
+}
+
+impl TimeFormat {
+    /// Date-times displayed like `2001-12-05T22:05:20Z`.
+    pub fn rfc3339() -> TimeFormat {
+        TimeFormat {
+            kind: TimeKind::Rfc3339,
+        }
+    }
+
+    /// Date-times displayed with a pattern, where `%Y`, `%m`, `%d`, `%H`, `%M` and `%S`
+    /// are replaced by the year, the month, the day, the hours, the minutes and the seconds,
+    /// and `%%` by `%`.
+    pub fn pattern(pattern: &str) -> TimeFormat {
+        TimeFormat {
+            kind: TimeKind::Pattern(pattern.to_string()),
+        }
+    }
+
+    /// Date-times displayed relatively to `now`, like `3 days ago` or `in 2 hours`.
+    pub fn relative(now: SystemTime) -> TimeFormat {
+        TimeFormat {
+            kind: TimeKind::Relative(now),
+        }
+    }
+
+    /// Returns the text of a date-time, or an empty text when the value is not a date-time.
+    pub fn format<V: Into<Value>>(&self, value: V) -> String {
+        self.format_value(&value.into()).unwrap_or_default()
+    }
+
+    /// Returns a cell displaying a date-time with this format, keeping it for the sorts.
+    pub fn cell<V: Into<Value>>(&self, value: V) -> Cell {
+        let value = value.into();
+        typed_cell(value.clone(), self.format_value(&value))
+    }
+
+    fn format_value(&self, value: &Value) -> Option<String> {
+        let Value::DateTime(time) = value else {
+            return None;
+        };
+
+        let text = match &self.kind {
+            TimeKind::Rfc3339 => value.to_string(),
+            TimeKind::Pattern(pattern) => format_pattern(pattern, *time),
+            TimeKind::Relative(now) => {
+                let (elapsed, future) = match now.duration_since(*time) {
+                    Ok(elapsed) => (elapsed, false),
+                    Err(err) => (err.duration(), true),
+                };
+                format_relative(elapsed, future)
+            }
+        };
+
+        Some(text)
+    }
+}
+
+fn format_pattern(pattern: &str, time: SystemTime) -> String {
+    let civil = Civil::from(time);
+    let mut text = String::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Y') => text += &format!("{:04}", civil.year),
+            Some('m') => text += &format!("{:02}", civil.month),
+            Some('d') => text += &format!("{:02}", civil.day),
+            Some('H') => text += &format!("{:02}", civil.hour),
+            Some('M') => text += &format!("{:02}", civil.minute),
+            Some('S') => text += &format!("{:02}", civil.second),
+            Some('%') => text.push('%'),
+            // the other sequences are kept as they are
+            Some(other) => text.extend(['%', other]),
+            None => text.push('%'),
+        }
+    }
+
+    text
+}
+
+fn format_relative(elapsed: Duration, future: bool) -> String {
+    let seconds = elapsed.as_secs();
+    if seconds < 60 {
+        return "just now".to_string();
+    }
+
+    let (length, unit) = RELATIVE_UNITS
+        .iter()
+        .find(|(length, _)| seconds >= *length)
+        .unwrap();
+    let count = seconds / length;
+    let plural = if count > 1 { "s" } else { "" };
+    match future {
+        true => format!("in {count} {unit}{plural}"),
+        false => format!("{count} {unit}{plural} ago"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::{DurationFormat, NumberFormat, TimeFormat};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_thousands() {
+        let format = NumberFormat::new().thousands(',');
+        assert_eq!(format.format(999), "999");
+        assert_eq!(format.format(-1000), "-1,000");
+        assert_eq!(format.format(i64::MAX), "9,223,372,036,854,775,807");
+        assert_eq!(format.clone().decimals(2).format(1234), "1,234.00");
+    }
+
+    #[test]
+    fn test_bytes() {
+        let format = NumberFormat::bytes();
+        assert_eq!(format.format(512), "512 B");
+        assert_eq!(format.format(1024), "1.0 KiB");
+        assert_eq!(format.format(1_048_575), "1.0 MiB");
+        assert_eq!(format.format(5 * 1024u64.pow(3)), "5.0 GiB");
+    }
+
+    #[test]
+    fn test_si() {
+        let format = NumberFormat::si("W");
+        assert_eq!(format.format(0), "0 W");
+        assert_eq!(format.format(950), "950 W");
+        assert_eq!(format.format(0.0025), "2.5 mW");
+        assert_eq!(format.format(-1500), "-1.5 kW");
+    }
+
+    #[test]
+    fn test_not_a_number() {
+        assert_eq!(NumberFormat::percent().format(true), "");
+        assert_eq!(NumberFormat::new().format(f64::NAN), "NaN");
+    }
+
+    #[test]
+    fn test_duration_units() {
+        let format = DurationFormat::humanized();
+        assert_eq!(format.format(Duration::ZERO), "0ms");
+        assert_eq!(format.format(Duration::from_secs(60)), "1m");
+        assert_eq!(format.format(Duration::from_millis(61_500)), "1m 1s");
+        assert_eq!(format.format(1.5), "");
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        let format = TimeFormat::relative(now);
+        assert_eq!(format.format(now - Duration::from_secs(30)), "just now");
+        assert_eq!(format.format(now - Duration::from_secs(60)), "1 minute ago");
+        assert_eq!(
+            format.format(now + Duration::from_secs(7_300)),
+            "in 2 hours"
+        );
+        assert_eq!(
+            format.format(now - Duration::from_secs(400 * 86_400)),
+            "1 year ago"
+        );
+    }
+
+    #[test]
+    fn test_pattern() {
+        let time = UNIX_EPOCH + Duration::from_millis(951_782_400_250);
+        let format = TimeFormat::pattern("%d/%m/%Y %H:%M:%S 100%% %q");
+        assert_eq!(format.format(time), "29/02/2000 00:00:00 100% %q");
+    }
+}
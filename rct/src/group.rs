@@ -0,0 +1,97 @@
+use crate::aggregate::{summarize, Aggregate};
+use crate::cell::Cell;
+use crate::row::Row;
+use crate::table::Table;
+use std::collections::HashMap;
+
+impl Table {
+    /// Returns a new table whose rows are grouped by the text of their cell at `column`, colors
+    /// being left out. Each group is a section below that cell, and ends with a subtotal row
+    /// made of the `subtotals` of its rows, when some are given. The groups keep the order
+    /// of their first row.
+    ///
+    /// The subtotal rows are marked, see [`Row::is_subtotal`]: they are left out of the CSV,
+    /// TSV and JSON exports, of [`Table::summary`], [`Table::filter`] and of a new grouping,
+    /// and removed by [`Table::sort_by`] along with the sections.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::aggregate::Aggregate;
+    /// use rct::cell::ICell;
+    /// use rct::styles::borders::BorderStyle;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .border(BorderStyle::Simple)
+    ///     .add_header(vec!["Genre".cell(), "Title".cell(), "Price".cell()])
+    ///     .add_row(vec!["Fantasy".cell(), "Harry Potter".cell(), "14.87".cell()])
+    ///     .add_row(vec!["Action".cell(), "Avenger".cell(), "18.50".cell()])
+    ///     .add_row(vec!["Fantasy".cell(), "The Hobbit".cell(), "12.10".cell()]);
+    ///
+    /// let report = table.group_by(0, &[(1, Aggregate::Count), (2, Aggregate::Sum)]);
+    /// assert_eq!(
+    ///     "\n".to_string() + &report.to_string(),
+    ///     r#"
+    /// +---------+--------------+-------+
+    /// | Genre   │ Title        │ Price |
+    /// +=========+==============+=======+
+    /// | Fantasy                        |
+    /// +---------+--------------+-------+
+    /// | Fantasy │ Harry Potter │ 14.87 |
+    /// +---------+--------------+-------+
+    /// | Fantasy │ The Hobbit   │ 12.10 |
+    /// +---------+--------------+-------+
+    /// |         │ 2            │ 26.97 |
+    /// +=========+==============+=======+
+    /// | Action                         |
+    /// +---------+--------------+-------+
+    /// | Action  │ Avenger      │ 18.50 |
+    /// +---------+--------------+-------+
+    /// |         │ 1            │ 18.50 |
+    /// +---------+--------------+-------+"#
+    /// );
+    /// ```
+    pub fn group_by(&self, column: usize, subtotals: &[(usize, Aggregate)]) -> Table {
+        let mut indexes: HashMap<String, usize> = HashMap::new();
+        let mut groups: Vec<Vec<&Row>> = vec![];
+        for row in self.data_rows() {
+            let key = row
+                .cells
+                .get(column)
+                .map(|cell| cell.plain_text())
+                .unwrap_or_default();
+            let index = *indexes.entry(key).or_insert_with(|| {
+                groups.push(vec![]);
+                groups.len() - 1
+            });
+            groups[index].push(row);
+        }
+
+        let columns = self.columns();
+        let mut table = Table {
+            title: self.title.clone(),
+            header: self.header.clone(),
+            groups: self.groups.clone(),
+            column_styles: self.column_styles.clone(),
+            footer: self.footer.clone(),
+            ..self.clone_settings()
+        };
+        for rows in groups {
+            // the cell of the first row heads the group, with its colors
+            let cell = rows[0].cells.get(column).cloned();
+            table.add_section(cell.unwrap_or_else(|| Cell::from_lines(vec![])));
+            for row in &rows {
+                table.add_row((*row).clone());
+            }
+            if !subtotals.is_empty() {
+                let mut subtotal = summarize(&rows, columns, subtotals);
+                subtotal.subtotal = true;
+                table.add_row(subtotal);
+            }
+        }
+
+        table
+    }
+}
@@ -29,7 +29,7 @@
 //!         .add_row(vec![1.cell(),"Harry \nPotter".cell(), "14.87".cell()])
 //!         .add_row(vec![2.cell(),"Spider-man".cell(),"18.80".cell()])
 //!         .add_row(vec![3.cell(), "Avenger".cell(), "18.50".cell()]);
-//!     table.view();
+//!     table.view().unwrap();
 //!
 //! ```
 //!
@@ -59,15 +59,15 @@
 //!         .add_row(vec![1.cell(),"Harry \nPotter".cell().color("#ff0000"), "14.87".cell()])
 //!         .add_row(vec![2.cell(),"Spider-man".cell(),"18.80".cell()])
 //!         .add_row(vec![3.cell(), "Avenger".cell(), "18.50".cell().font(Font::Italic)]);
-//!     table.view();
+//!     table.view().unwrap();
 //!
 //! ```
 //! ![Styled Table](../images/style_table.png)
 //!
 //! ### Use derive macro
-//! `#[derive(ToTable)]` can be used to display a `Vec` or slice of `struct` as a table.
+//! `#[derive(ToTable)]` implements [`ToTable`], so a `Vec`, a slice or an iterator of `struct` can be displayed as a table with [`IntoTable`].
 //! ```rust, no_run
-//! use rct::ToTable;
+//! use rct::{IntoTable, ToTable};
 //!
 //! #[derive(ToTable)]
 //! struct Movies {
@@ -101,18 +101,39 @@
 //! ## Features
 //!
 //! - `derive`: Enables derive macro for creating tables using structs.
+//! - `chrono`: Converts the date-times of `chrono` into typed values.
+//! - `time`: Converts the date-times of `time` into typed values.
+//! - `tui`: Displays tables in an interactive full-screen viewer, with `ratatui`.
 
+pub mod aggregate;
 pub mod cell;
+mod expanded;
+mod export;
+pub mod format;
+mod group;
+pub mod page;
 pub mod row;
+pub mod sort;
+mod split;
+pub mod stream;
 pub mod styles;
 pub mod table;
+mod terminal;
+mod text;
+pub mod to_table;
+pub mod value;
+#[cfg(feature = "tui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tui")))]
+pub mod viewer;
 
 #[cfg(feature = "derive")]
-#[cfg_attr(feature = "doc", doc(cfg(feature = "derive")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use rct_derive::ToTable;
 
 pub use self::{
     cell::{Cell, ICell},
     row::Row,
     table::Table,
+    to_table::{IntoTable, ToTable},
+    value::Value,
 };
@@ -0,0 +1,194 @@
+//! Splitting a [`Table`] in pages, and displaying it through a pager.
+use crate::cell::Cell;
+use crate::row::Row;
+use crate::table::{Layout, Table};
+use crate::terminal;
+use std::io::{self, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+/// How many rows a page holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageSize {
+    /// At most the given number of rows.
+    Rows(usize),
+    /// As many rows as fit in the given number of lines, borders included, the table being
+    /// laid out horizontally. A row taller than a page is alone on its page.
+    Lines(usize),
+}
+
+impl PageSize {
+    /// Pages as tall as the terminal the standard output is displayed on. When it is not
+    /// a terminal, the height is read from the `LINES` environment variable, 24 lines being
+    /// assumed when it is not set.
+    pub fn terminal() -> PageSize {
+        PageSize::Lines(terminal::height())
+    }
+}
+
+impl Table {
+    /// Returns the table split in pages, each one with the title, the header and the borders
+    /// of the table. The columns keep the width they have in the whole table, the sections
+    /// are displayed on the page of their first row, and the footer is on the last page.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::page::PageSize;
+    /// use rct::styles::borders::BorderStyle;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .border(BorderStyle::Simple)
+    ///     .add_header(vec!["ID".cell(), "Title".cell()])
+    ///     .add_row(vec![1.cell(), "Harry Potter".cell()])
+    ///     .add_row(vec![2.cell(), "Avenger".cell()])
+    ///     .add_row(vec![3.cell(), "Spider-man".cell()]);
+    ///
+    /// let pages = table.pages(PageSize::Rows(2));
+    /// assert_eq!(pages.len(), 2);
+    /// assert_eq!(
+    ///     "\n".to_string() + &pages[1].to_string(),
+    ///     r#"
+    /// +----+--------------+
+    /// | ID │ Title        |
+    /// +----+--------------+
+    /// | 3  │ Spider-man   |
+    /// +----+--------------+"#
+    /// );
+    /// ```
+    pub fn pages(&self, size: PageSize) -> Vec<Table> {
+        let mut blank = Table {
+            title: self.title.clone(),
+            header: self.header.clone(),
+            groups: self.groups.clone(),
+            column_styles: self.column_styles.clone(),
+            ..self.clone_settings()
+        };
+        for (index, width) in self.set_max_width().into_iter().enumerate() {
+            blank.width_column(index, width.saturating_sub(2));
+        }
+        // the lines of a page around its rows: a page holding a row of empty cells, less that
+        // row and its separator, since the first row of a table without header has none
+        let mut frame = blank.clone();
+        frame.layout = Layout::Horizontal;
+        frame
+            .rows
+            .push(Row::from(vec![Cell::from_lines(vec![]); self.columns()]));
+        let start = page_height(&frame).saturating_sub(2);
+        // every row and every section is displayed below a separator
+        let row_height = |row: &Row| 1 + line_count(&blank.fit(row).cells);
+        let section_height = |cell: &Cell| 1 + line_count(std::slice::from_ref(cell));
+        let fits = |rows: usize, used: usize| match size {
+            PageSize::Rows(size) => rows <= size,
+            PageSize::Lines(lines) => used <= lines,
+        };
+
+        let mut pages = vec![];
+        let mut page = blank.clone();
+        let mut used = start;
+        let mut sections = self.sections.iter().peekable();
+        for (index, row) in self.rows.iter().enumerate() {
+            let first_section = page.sections.len();
+            let mut height = row_height(row);
+            while let Some((_, cell)) = sections.next_if(|(start, _)| *start == index) {
+                page.sections.push((page.rows.len(), cell.clone()));
+                height += section_height(cell);
+            }
+            page.rows.push(row.clone());
+            used += height;
+            if page.rows.len() > 1 && !fits(page.rows.len(), used) {
+                // the row and its sections start the next page
+                let row = page.rows.pop().unwrap();
+                let moved = page.sections.split_off(first_section);
+                pages.push(std::mem::replace(&mut page, blank.clone()));
+                page.sections = moved.into_iter().map(|(_, cell)| (0, cell)).collect();
+                page.rows.push(row);
+                used = start + height;
+            }
+        }
+
+        if let Some(footer) = &self.footer {
+            page.footer = Some(footer.clone());
+            if !page.rows.is_empty() && !fits(page.rows.len(), used + row_height(footer)) {
+                page.footer = None;
+                pages.push(std::mem::replace(&mut page, blank));
+                page.footer = Some(footer.clone());
+            }
+        }
+        pages.push(page);
+
+        pages
+    }
+
+    /// Display the table on terminal through a pager when the standard output is a terminal
+    /// and the table is taller than it. The pager is the `PAGER` environment variable,
+    /// `less -RS` by default; the table is displayed directly when it cannot be started.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error met while writing on the standard output or in the pager.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// for id in 0..100 {
+    ///     table.add_row(vec![id.cell(), "Harry Potter".cell()]);
+    /// }
+    ///
+    /// table.view_paged().unwrap();
+    /// ```
+    pub fn view_paged(&self) -> io::Result<()> {
+        let mut view = String::new();
+        self.fmt_to(&mut view).map_err(io::Error::other)?;
+
+        let stdout = io::stdout();
+        if stdout.is_terminal() && view.lines().count() > terminal::height() {
+            let pager = std::env::var("PAGER")
+                .ok()
+                .filter(|pager| !pager.trim().is_empty())
+                .unwrap_or_else(|| "less -RS".to_string());
+            let mut words = pager.split_whitespace();
+            let program = words.next().unwrap_or("less");
+            let pager = Command::new(program)
+                .args(words)
+                .stdin(Stdio::piped())
+                .spawn();
+            if let Ok(mut pager) = pager {
+                let written = pager.stdin.take().unwrap().write_all(view.as_bytes());
+                pager.wait()?;
+                return match written {
+                    // the pager was closed before reading the whole table
+                    Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+                    written => written,
+                };
+            }
+        }
+
+        stdout.lock().write_all(view.as_bytes())
+    }
+}
+
+/// Returns the number of lines of the tallest cell, a row taking at least one line.
+fn line_count(cells: &[Cell]) -> usize {
+    cells
+        .iter()
+        .map(|cell| cell.data.len())
+        .max()
+        .unwrap_or(0)
+        .max(1)
+}
+
+/// Returns the number of lines of a table.
+fn page_height(table: &Table) -> usize {
+    let mut view = String::new();
+    match table.fmt_to(&mut view) {
+        Ok(()) => view.lines().count(),
+        Err(_) => usize::MAX,
+    }
+}
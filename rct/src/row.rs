@@ -1,13 +1,19 @@
 use crate::cell::Cell;
 
+/// A row of cells, built from them with `Row::from(cells)` or `cells.into()`.
 #[derive(Debug, Clone)]
 pub struct Row {
     pub cells: Vec<Cell>,
+    /// Whether the row is the subtotal of a group.
+    pub(crate) subtotal: bool,
 }
 
 impl From<Vec<Cell>> for Row {
     fn from(value: Vec<Cell>) -> Self {
-        Row { cells: value }
+        Row {
+            cells: value,
+            subtotal: false,
+        }
     }
 }
 
@@ -16,4 +22,9 @@ impl Row {
     pub fn width(&self) -> Vec<usize> {
         self.cells.iter().map(|cells| cells.width).collect()
     }
+
+    /// Whether the row is the subtotal of a group, added by `Table::group_by`.
+    pub fn is_subtotal(&self) -> bool {
+        self.subtotal
+    }
 }
@@ -0,0 +1,296 @@
+//! Sorting the rows of a [`Table`] by the text of their cells.
+use crate::row::Row;
+use crate::table::Table;
+use crate::value::Value;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+/// Direction of a sort.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Order {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// Function comparing two texts, shared by the clones of a [`Compare::With`].
+pub type CompareFn = Arc<dyn Fn(&str, &str) -> Ordering + Send + Sync>;
+
+/// How the texts of two cells are compared, colors being left out.
+///
+/// The natural and numeric comparisons use the typed values of the cells instead: the typed
+/// cells come first, ordered by kind and by value, and then the other cells by their texts.
+#[derive(Clone, Default)]
+pub enum Compare {
+    /// Numbers inside the texts are compared by value, so that `file9` comes before `file10`.
+    #[default]
+    Natural,
+    /// The texts are compared as numbers, the texts that are not numbers being the greatest.
+    Numeric,
+    /// The texts are compared character by character.
+    Lexicographic,
+    /// The texts are compared with a function, which may capture its state, see [`Compare::with`].
+    With(CompareFn),
+}
+
+impl fmt::Debug for Compare {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Compare::Natural => f.write_str("Natural"),
+            Compare::Numeric => f.write_str("Numeric"),
+            Compare::Lexicographic => f.write_str("Lexicographic"),
+            Compare::With(_) => f.write_str("With(..)"),
+        }
+    }
+}
+
+impl Compare {
+    /// Compare the texts with a function or a closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::sort::Compare;
+    /// use std::cmp::Ordering;
+    ///
+    /// let order = ["low", "medium", "high"];
+    /// let compare = Compare::with(move |a, b| {
+    ///     let rank = |text: &str| order.iter().position(|level| *level == text);
+    ///     rank(a).cmp(&rank(b))
+    /// });
+    /// assert_eq!(compare.compare("high", "low"), Ordering::Greater);
+    /// ```
+    pub fn with<F>(compare: F) -> Compare
+    where
+        F: Fn(&str, &str) -> Ordering + Send + Sync + 'static,
+    {
+        Compare::With(Arc::new(compare))
+    }
+
+    /// Compares two texts.
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        match self {
+            Compare::Natural => natural(a, b),
+            Compare::Numeric => numeric(a, b),
+            Compare::Lexicographic => a.cmp(b),
+            Compare::With(compare) => compare(a, b),
+        }
+    }
+
+    /// Compares two cells by their typed values when they can be, by their texts otherwise.
+    /// The typed cells come before the others, for the order to stay consistent when a column
+    /// mixes both.
+    fn compare_cells(&self, (a, a_value): &SortText, (b, b_value): &SortText) -> Ordering {
+        match (self, a_value, b_value) {
+            (Compare::Natural | Compare::Numeric, Some(a), Some(b)) => a.total_cmp(b),
+            (Compare::Natural | Compare::Numeric, Some(_), None) => Ordering::Less,
+            (Compare::Natural | Compare::Numeric, None, Some(_)) => Ordering::Greater,
+            _ => self.compare(a, b),
+        }
+    }
+}
+
+/// The text of a cell without colors, and its typed value.
+type SortText = (String, Option<Value>);
+
+/// A column to sort the rows by.
+///
+/// # Examples
+///
+/// ```
+/// use rct::sort::{Compare, SortKey};
+///
+/// let key = SortKey::desc(2).compare(Compare::Numeric);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SortKey {
+    column: usize,
+    order: Order,
+    compare: Compare,
+}
+
+impl SortKey {
+    /// Sort by the column at `column`, in the given order, with a natural comparison.
+    pub fn new(column: usize, order: Order) -> SortKey {
+        SortKey {
+            column,
+            order,
+            compare: Compare::Natural,
+        }
+    }
+
+    /// Sort by the column at `column` in ascending order.
+    pub fn asc(column: usize) -> SortKey {
+        SortKey::new(column, Order::Ascending)
+    }
+
+    /// Sort by the column at `column` in descending order.
+    pub fn desc(column: usize) -> SortKey {
+        SortKey::new(column, Order::Descending)
+    }
+
+    /// Choose how the cells of the column are compared.
+    pub fn compare(mut self, compare: Compare) -> SortKey {
+        self.compare = compare;
+
+        self
+    }
+}
+
+impl Table {
+    /// Sort the rows by the column at `index`, with a natural comparison.
+    /// The header, the title, the group header and the footer stay in place, the sections
+    /// and the subtotal rows are removed, see [`Table::sort_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::sort::Order;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .add_header(vec!["File".cell()])
+    ///     .add_row(vec!["file10".cell()])
+    ///     .add_row(vec!["file9".cell()])
+    ///     .sort_by_column(0, Order::Ascending);
+    ///
+    /// assert_eq!(table.rows()[0].cells[0].to_string(), "file9");
+    /// ```
+    pub fn sort_by_column(&mut self, index: usize, order: Order) -> &mut Table {
+        self.sort_by(&[SortKey::new(index, order)])
+    }
+
+    /// Sort the rows by several columns, the next key being used when the previous ones
+    /// are equal. Rows equal on every key keep their order. The header and the footer stay
+    /// in place. The sections and the subtotal rows are removed, since the rows they started
+    /// or summed up are moved: sort the rows before adding sections or calling
+    /// [`Table::group_by`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::sort::{Compare, SortKey};
+    /// use rct::styles::color::Colorizer;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .add_header(vec!["Genre".cell(), "Price".cell()])
+    ///     .add_row(vec!["Fantasy".cell(), "9.5".cell()])
+    ///     .add_row(vec!["Action".cell(), "18.80".cell()])
+    ///     .add_row(vec!["Action".cell(), "18.50".cell().color("#ff0000")])
+    ///     .sort_by(&[SortKey::asc(0), SortKey::desc(1).compare(Compare::Numeric)]);
+    ///
+    /// let prices: Vec<_> = table.rows().iter().map(|row| row.cells[1].data[0].clone()).collect();
+    /// assert_eq!(prices, ["18.80", "\u{1b}[38;2;255;0;0m18.50\u{1b}[0m", "9.5"]);
+    /// ```
+    pub fn sort_by(&mut self, keys: &[SortKey]) -> &mut Table {
+        let texts = |row: &Row| {
+            keys.iter()
+                .map(|key| match row.cells.get(key.column) {
+                    Some(cell) => (cell.plain_text(), cell.value().cloned()),
+                    None => (String::new(), None),
+                })
+                .collect::<Vec<_>>()
+        };
+        let mut rows = std::mem::take(&mut self.rows)
+            .into_iter()
+            .filter(|row| !row.subtotal)
+            .map(|row| (texts(&row), row))
+            .collect::<Vec<_>>();
+
+        rows.sort_by(|(a, _), (b, _)| {
+            keys.iter()
+                .zip(a.iter().zip(b.iter()))
+                .map(|(key, (a, b))| match key.order {
+                    Order::Ascending => key.compare.compare_cells(a, b),
+                    Order::Descending => key.compare.compare_cells(b, a),
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+        self.rows = rows.into_iter().map(|(_, row)| row).collect();
+        self.sections.clear();
+
+        self
+    }
+}
+
+/// Compares two texts as numbers, the texts that are not numbers being greater than the numbers.
+fn numeric(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.total_cmp(&b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+/// Compares two texts chunk by chunk, runs of digits being compared by value.
+fn natural(a: &str, b: &str) -> Ordering {
+    let (mut a_chunks, mut b_chunks) = (chunks(a), chunks(b));
+    loop {
+        let ordering = match (a_chunks.next(), b_chunks.next()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) if is_digits(a) && is_digits(b) => {
+                let (a, b) = (a.trim_start_matches('0'), b.trim_start_matches('0'));
+                a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+            }
+            (Some(a), Some(b)) => a.cmp(b),
+        };
+        if ordering.is_ne() {
+            return ordering;
+        }
+    }
+}
+
+/// Splits a text in runs of digits and runs of other characters.
+fn chunks(text: &str) -> impl Iterator<Item = &str> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let end = rest
+            .find(|c: char| c.is_ascii_digit() != first.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (chunk, tail) = rest.split_at(end);
+        rest = tail;
+        Some(chunk)
+    })
+}
+
+fn is_digits(chunk: &str) -> bool {
+    chunk.starts_with(|c: char| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sort::{natural, numeric};
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_natural_numbers() {
+        assert_eq!(natural("file9", "file10"), Ordering::Less);
+        assert_eq!(natural("file010", "file9"), Ordering::Greater);
+        assert_eq!(natural("v1.2", "v1.10"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_natural_text() {
+        assert_eq!(natural("ab", "abc"), Ordering::Less);
+        assert_eq!(natural("b1", "a2"), Ordering::Greater);
+        assert_eq!(natural("a01", "a1"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_numeric() {
+        assert_eq!(numeric("9.5", "18.80"), Ordering::Less);
+        assert_eq!(numeric("-1", "-10"), Ordering::Greater);
+        assert_eq!(numeric("n/a", "100"), Ordering::Greater);
+    }
+}
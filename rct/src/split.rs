@@ -0,0 +1,114 @@
+//! Splitting a wide [`Table`] in stacked tables, repeating its frozen columns in each one.
+use crate::cell::Cell;
+use crate::table::{Layout, Table};
+use std::fmt;
+
+impl Table {
+    /// Returns the table split in chunks of columns no wider than `width`, borders included,
+    /// to be displayed one below the other. The frozen columns, see [`Table::freeze_column`],
+    /// are the first ones of every chunk, followed by as many other columns as fit, at least one.
+    /// The chunks keep the title, the sections, the footer and the settings of the table, and
+    /// are labelled with their columns with [`Table::chunk_label`]; the group header is not kept
+    /// and the chunks are laid out horizontally.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::styles::borders::BorderStyle;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .border(BorderStyle::Simple)
+    ///     .freeze_column(0)
+    ///     .chunk_label(true)
+    ///     .add_header(vec!["ID".cell(), "Title".cell(), "Price".cell()])
+    ///     .add_row(vec![1.cell(), "Harry Potter".cell(), "14.87".cell()]);
+    ///
+    /// let chunks = table.split_columns(24);
+    /// assert_eq!(chunks.len(), 2);
+    /// assert_eq!(
+    ///     "\n".to_string() + &chunks[1].to_string(),
+    ///     r#"
+    /// +---------------+
+    /// | column 3 of 3 |
+    /// +----+----------+
+    /// | ID │ Price    |
+    /// +----+----------+
+    /// | 1  │ 14.87    |
+    /// +----+----------+"#
+    /// );
+    /// ```
+    pub fn split_columns(&self, width: usize) -> Vec<Table> {
+        let columns = self.columns();
+        let all = (0..columns).collect::<Vec<_>>();
+        // the title and the sections would widen the last column of the whole table
+        let widths = Table {
+            title: None,
+            sections: vec![],
+            ..self.select(&all)
+        }
+        .set_max_width();
+        let (frozen, others): (Vec<usize>, Vec<usize>) = all.into_iter().partition(|index| {
+            self.column_styles
+                .get(*index)
+                .is_some_and(|style| style.frozen)
+        });
+
+        // every column takes its width and the border on its left
+        let frozen_width = 1 + frozen.iter().map(|index| widths[*index] + 1).sum::<usize>();
+        let mut chunks: Vec<Vec<usize>> = vec![];
+        let mut used = frozen_width;
+        for index in others {
+            match chunks.last_mut() {
+                Some(chunk) if used + widths[index] < width => chunk.push(index),
+                _ => {
+                    chunks.push(vec![index]);
+                    used = frozen_width;
+                }
+            }
+            used += widths[index] + 1;
+        }
+        if chunks.is_empty() {
+            chunks.push(vec![]);
+        }
+
+        chunks
+            .iter()
+            .map(|chunk| {
+                let selected = frozen.iter().chain(chunk).copied().collect::<Vec<_>>();
+                let mut table = self.select(&selected);
+                table.layout = Layout::Horizontal;
+                if let (true, Some(first), Some(last)) =
+                    (self.chunk_label, chunk.first(), chunk.last())
+                {
+                    let label = match first == last {
+                        true => format!("column {} of {columns}", first + 1),
+                        false => format!("columns {}–{} of {columns}", first + 1, last + 1),
+                    };
+                    let mut lines = table
+                        .title
+                        .take()
+                        .map(|title| title.data)
+                        .unwrap_or_default();
+                    lines.push(label);
+                    table.title = Some(Cell::from_lines(lines));
+                }
+                table
+            })
+            .collect()
+    }
+
+    /// print the chunks of the table one below the other, separated by an empty line.
+    pub(crate) fn print_split<W: fmt::Write>(&self, f: &mut W, width: usize) -> fmt::Result {
+        for (index, chunk) in self.split_columns(width).iter().enumerate() {
+            if index > 0 {
+                f.write_char('\n')?;
+            }
+            chunk.fmt_to(f)?;
+        }
+
+        Ok(())
+    }
+}
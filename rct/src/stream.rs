@@ -0,0 +1,245 @@
+use crate::format::Format;
+use crate::row::Row;
+use crate::styles::align::Align;
+use crate::styles::borders::BorderStyle;
+use crate::table::{write_io, Overflow, Table};
+use std::io;
+
+/// How a [`StreamTable`] chooses the width of its columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Widths {
+    /// The width of the contents of every column, known up front.
+    Fixed(Vec<usize>),
+    /// Keep the first rows in memory and size the columns on them and on the header.
+    Estimate(usize),
+}
+
+/// A table written row by row, for sources whose rows are not all known up front.
+///
+/// The top border and the header are written as soon as the width of the columns is known,
+/// then every row is written when it is pushed. Cells wider than their column are wrapped,
+/// or truncated with [`StreamTable::overflow`]. The bottom border is written by
+/// [`StreamTable::finish`], or when the table is dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use rct::cell::ICell;
+/// use rct::stream::{StreamTable, Widths};
+///
+/// let mut stream = StreamTable::new(vec![], Widths::Fixed(vec![2, 10]));
+/// stream.add_header(vec!["ID".cell(), "Title".cell()]).unwrap();
+/// stream.push(vec![1.cell(), "Harry Potter".cell()]).unwrap();
+/// let view = stream.finish().unwrap();
+///
+/// assert_eq!(
+///     String::from_utf8(view).unwrap(),
+///     "╔════╤════════════╗
+/// ║ ID │ Title      ║
+/// ╟────┼────────────╢
+/// ║ 1  │ Harry      ║
+/// ║    │ Potter     ║
+/// ╚════╧════════════╝
+/// "
+/// );
+/// ```
+pub struct StreamTable<W: io::Write> {
+    writer: Option<W>,
+    /// Settings of the table, then the header and rows kept while estimating the widths.
+    table: Table,
+    widths: Widths,
+    column_len: Option<Vec<usize>>,
+    written: usize,
+    closed: bool,
+}
+
+impl<W: io::Write> StreamTable<W> {
+    /// Create a new streaming table writing in `writer`.
+    /// Wrap unbuffered writers such as files in a [`io::BufWriter`].
+    pub fn new(writer: W, widths: Widths) -> StreamTable<W> {
+        Self {
+            writer: Some(writer),
+            table: Table::new(),
+            widths,
+            column_len: None,
+            written: 0,
+            closed: false,
+        }
+    }
+
+    /// Change the style of the borders. It has to be called before any row is written.
+    pub fn border(&mut self, border: BorderStyle) -> &mut StreamTable<W> {
+        self.table.border(border);
+
+        self
+    }
+
+    /// Align the contents of every column.
+    pub fn align(&mut self, align: Align) -> &mut StreamTable<W> {
+        self.table.align(align);
+
+        self
+    }
+
+    /// Align the contents of the column at `index`.
+    pub fn align_column(&mut self, index: usize, align: Align) -> &mut StreamTable<W> {
+        self.table.align_column(index, align);
+
+        self
+    }
+
+    /// Display the typed values of the column at `index` with `format`.
+    pub fn format_column<F: Into<Format>>(
+        &mut self,
+        index: usize,
+        format: F,
+    ) -> &mut StreamTable<W> {
+        self.table.format_column(index, format);
+
+        self
+    }
+
+    /// Limit the width estimated with [`Widths::Estimate`] to `width`.
+    pub fn max_width(&mut self, width: usize) -> &mut StreamTable<W> {
+        self.table.max_width(width);
+
+        self
+    }
+
+    /// Choose how cells wider than their column are displayed.
+    pub fn overflow(&mut self, overflow: Overflow) -> &mut StreamTable<W> {
+        self.table.overflow(overflow);
+
+        self
+    }
+
+    /// Add the header of the table, written right away when the widths are fixed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`io::ErrorKind::InvalidInput`] error when the table already has a header,
+    /// or when rows were already written, and the error met while writing the header.
+    pub fn add_header<R: Into<Row>>(&mut self, row: R) -> io::Result<&mut StreamTable<W>> {
+        if self.column_len.is_some() || self.table.header.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the header has to be added once, before any row is written",
+            ));
+        }
+        self.table.add_header(row);
+        if let Widths::Fixed(widths) = &self.widths {
+            let column_len = widths.iter().map(|width| width + 2).collect();
+            self.start(column_len)?;
+            self.writer.as_mut().unwrap().flush()?;
+        }
+
+        Ok(self)
+    }
+
+    /// Write a new row, or keep it until enough rows are known to estimate the widths.
+    pub fn push<R: Into<Row>>(&mut self, row: R) -> io::Result<&mut StreamTable<W>> {
+        let row = row.into();
+        match (&self.column_len, &self.widths) {
+            (Some(_), _) => self.write_row(&row)?,
+            (None, Widths::Fixed(widths)) => {
+                let column_len = widths.iter().map(|width| width + 2).collect();
+                self.start(column_len)?;
+                self.write_row(&row)?;
+            }
+            (None, Widths::Estimate(count)) => {
+                let count = *count;
+                self.table.add_row(row);
+                if self.table.rows.len() >= count {
+                    self.start(self.table.set_max_width())?;
+                }
+            }
+        }
+        if self.column_len.is_some() {
+            self.writer.as_mut().unwrap().flush()?;
+        }
+
+        Ok(self)
+    }
+
+    /// Write the bottom border and returns the writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.close()?;
+
+        Ok(self.writer.take().unwrap())
+    }
+
+    /// Write the top border, the header and the rows kept so far.
+    fn start(&mut self, column_len: Vec<usize>) -> io::Result<()> {
+        let writer = self.writer.as_mut().unwrap();
+        write_io(writer, |f| self.table.print_header(f, &column_len))?;
+        self.column_len = Some(column_len);
+
+        let header = self.table.header.take();
+        let rows = std::mem::take(&mut self.table.rows);
+        for row in header.iter().chain(rows.iter()) {
+            self.write_row(row)?;
+        }
+
+        Ok(())
+    }
+
+    /// Write a row, fitted in the width of the columns, after a separator.
+    fn write_row(&mut self, row: &Row) -> io::Result<()> {
+        let column_len = self.column_len.as_ref().unwrap();
+        let cells = row
+            .cells
+            .iter()
+            .zip(column_len)
+            .enumerate()
+            .map(|(index, (cell, len))| {
+                let width = len.saturating_sub(2);
+                let cell = self.table.format_cell(index, cell);
+                match self.table.column_overflow(index) {
+                    _ if cell.width <= width => cell.into_owned(),
+                    Overflow::Wrap => cell.wrap(width),
+                    Overflow::Truncate => cell.truncate(width),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let writer = self.writer.as_mut().unwrap();
+        let written = self.written;
+        write_io(writer, |f| {
+            if written > 0 {
+                self.table.print_table_middle(f, column_len)?;
+            }
+            self.table.print_line(f, &Row::from(cells), column_len)
+        })?;
+        self.written += 1;
+
+        Ok(())
+    }
+
+    /// Write what is left of the table, once.
+    fn close(&mut self) -> io::Result<()> {
+        if self.closed {
+            return Ok(());
+        }
+        self.closed = true;
+
+        if self.column_len.is_none() {
+            let column_len = match &self.widths {
+                Widths::Fixed(widths) => widths.iter().map(|width| width + 2).collect(),
+                Widths::Estimate(_) => self.table.set_max_width(),
+            };
+            self.start(column_len)?;
+        }
+
+        let column_len = self.column_len.take().unwrap_or_default();
+        let writer = self.writer.as_mut().unwrap();
+        write_io(writer, |f| self.table.print_bottom(f, &column_len))?;
+        writer.flush()
+    }
+}
+
+impl<W: io::Write> Drop for StreamTable<W> {
+    fn drop(&mut self) {
+        // errors cannot be reported from drop, call `finish` to get them
+        let _ = self.close();
+    }
+}
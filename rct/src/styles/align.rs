@@ -0,0 +1,19 @@
+/// Horizontal alignment of the contents of a column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Align {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl Align {
+    /// Splits `padding` spaces between the left and the right of a value.
+    pub(crate) fn split(&self, padding: usize) -> (usize, usize) {
+        match self {
+            Align::Left => (0, padding),
+            Align::Center => (padding / 2, padding - padding / 2),
+            Align::Right => (padding, 0),
+        }
+    }
+}
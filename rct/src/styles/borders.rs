@@ -15,12 +15,21 @@ pub struct Border {
     pub(crate) right_mid: char,
     pub(crate) mid: char,
     pub(crate) mid_mid: char,
+    /// Junction of a middle line with a column border below it only, under a group header.
+    pub(crate) mid_top: char,
+    /// Line between two sections of the table, such as the groups of rows or the footer.
+    pub(crate) left_section: char,
+    pub(crate) section: char,
+    pub(crate) section_mid: char,
+    /// Junction of a section line with a column border above it only.
+    pub(crate) section_bottom: char,
+    pub(crate) right_section: char,
 }
 
 pub enum BorderStyle {
     Default,
     Simple,
-    Empty,  
+    Empty,
 }
 
 impl Border {
@@ -41,6 +50,12 @@ impl Border {
             right_mid: '+',
             mid: '-',
             mid_mid: '+',
+            mid_top: '+',
+            left_section: '+',
+            section: '=',
+            section_mid: '+',
+            section_bottom: '+',
+            right_section: '+',
         }
     }
 
@@ -61,6 +76,12 @@ impl Border {
             right_mid: ' ',
             mid: ' ',
             mid_mid: ' ',
+            mid_top: ' ',
+            left_section: ' ',
+            section: ' ',
+            section_mid: ' ',
+            section_bottom: ' ',
+            right_section: ' ',
         }
     }
 }
@@ -83,6 +104,12 @@ impl Default for Border {
             right_mid: '╢',
             mid: '─',
             mid_mid: '┼',
+            mid_top: '┬',
+            left_section: '╠',
+            section: '═',
+            section_mid: '╪',
+            section_bottom: '╧',
+            right_section: '╣',
         }
     }
 }
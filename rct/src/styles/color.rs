@@ -1,5 +1,4 @@
 use crate::cell::Cell;
-use regex::Regex;
 use std::str;
 
 pub trait Colorizer {
@@ -8,6 +7,7 @@ pub trait Colorizer {
     fn font(&self, font: Font) -> Cell;
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Font {
     Bold = 1,
     Light = 2,
@@ -20,6 +20,69 @@ pub enum Font {
     Strikethrough = 9,
 }
 
+/// Colors and fonts applied together to a cell, e.g. by `#[table(style_with = "...")]`.
+///
+/// # Examples
+///
+/// ```
+/// use rct::cell::ICell;
+/// use rct::styles::color::{Colorizer, Font, Style};
+///
+/// let style = Style::new().color("#ff0000").font(Font::Bold);
+/// let cell = style.apply(&"late".cell());
+/// assert_eq!(cell.to_string(), "late".cell().font(Font::Bold).color("#ff0000").to_string());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Style {
+    color: Option<String>,
+    bg: Option<String>,
+    fonts: Vec<Font>,
+}
+
+impl Style {
+    /// Create a style changing nothing.
+    pub fn new() -> Style {
+        Style::default()
+    }
+
+    /// Set the hexadecimal color of the text.
+    pub fn color(mut self, hex: &str) -> Style {
+        self.color = Some(hex.to_string());
+
+        self
+    }
+
+    /// Set the hexadecimal color of the background.
+    pub fn bg(mut self, hex: &str) -> Style {
+        self.bg = Some(hex.to_string());
+
+        self
+    }
+
+    /// Add a font, several fonts can be combined.
+    pub fn font(mut self, font: Font) -> Style {
+        self.fonts.push(font);
+
+        self
+    }
+
+    /// Returns the cell with the style applied.
+    pub fn apply(&self, cell: &Cell) -> Cell {
+        let mut cell = cell.clone();
+        for font in &self.fonts {
+            cell = cell.font(*font);
+        }
+        if let Some(bg) = &self.bg {
+            cell = cell.bg(bg);
+        }
+        if let Some(color) = &self.color {
+            cell = cell.color(color);
+        }
+
+        cell
+    }
+}
+
 impl Colorizer for Cell {
     /// Colorizes [Cell] with hex color.
     ///
@@ -43,11 +106,7 @@ impl Colorizer for Cell {
         }
 
         // Return a new Cell with the colored data and the original height and width
-        Cell {
-            data,
-            height: self.height,
-            width: self.width,
-        }
+        self.with_data(data)
     }
 
     /// Colorizes [Cell] with hex color.
@@ -72,11 +131,7 @@ impl Colorizer for Cell {
         }
 
         // Return a new Cell with the colored data and the original height and width
-        Cell {
-            data,
-            height: self.height,
-            width: self.width,
-        }
+        self.with_data(data)
     }
 
     /// Colorizes [Cell] with hex color.
@@ -102,11 +157,7 @@ impl Colorizer for Cell {
         }
 
         // Return a new Cell with the colored data and the original height and width
-        Cell {
-            data,
-            height: self.height,
-            width: self.width,
-        }
+        self.with_data(data)
     }
 }
 
@@ -129,6 +180,24 @@ fn new_ansi(hex: &str, value: usize) -> String {
     color
 }
 
+/// Parses a hexadecimal color like `#00ff00` in its red, green and blue components.
+/// ```
+/// use rct::styles::color::hex_rgb;
+///
+/// assert_eq!(hex_rgb("#ff8000"), Some((255, 128, 0)));
+/// assert_eq!(hex_rgb("red"), None);
+/// assert_eq!(hex_rgb("#zzzzzz"), None);
+/// ```
+pub fn hex_rgb(hex: &str) -> Option<(u8, u8, u8)> {
+    let digits = hex.strip_prefix('#')?;
+    if digits.len() != 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let component = |index: usize| u8::from_str_radix(&digits[index..index + 2], 16).ok();
+
+    Some((component(0)?, component(2)?, component(4)?))
+}
+
 /// Transforms string colored to string.
 /// ```
 /// use rct::styles::color::split_colors;
@@ -138,9 +207,8 @@ fn new_ansi(hex: &str, value: usize) -> String {
 /// assert_eq!(split_color, "string")
 /// ```
 pub fn split_colors(color: &str) -> String {
-    let re = Regex::new(r"\x1B\[([0-9]{1,2}(;[0-9]{1,2})?)?[m|K]").unwrap();
-
-    if re.is_match(color) {
+    // every ANSI sequence starts with ESC, text without it has nothing to strip
+    if color.contains('\x1b') {
         let strip_ansi_escapes = strip_ansi_escapes::strip(color).unwrap();
         let color = str::from_utf8(&strip_ansi_escapes).unwrap();
         return color.to_string();
@@ -152,7 +220,7 @@ pub fn split_colors(color: &str) -> String {
 #[cfg(test)]
 mod tests {
     use crate::cell::ICell;
-    use crate::styles::color::{split_colors, Colorizer, Font};
+    use crate::styles::color::{split_colors, Colorizer, Font, Style};
 
     #[test]
     fn test_colorize_white() {
@@ -183,6 +251,22 @@ mod tests {
         assert_eq!(colour.to_string(), "string\u{1b}[0m")
     }
     #[test]
+    fn test_style_empty() {
+        let cell = Style::new().apply(&"string".cell());
+        assert_eq!(cell.to_string(), "string")
+    }
+    #[test]
+    fn test_style_bg_font() {
+        let cell = Style::new()
+            .bg("#000000")
+            .font(Font::Italic)
+            .apply(&"string".cell());
+        assert_eq!(
+            cell.to_string(),
+            "\u{1b}[48;2;0;0;0m\u{1b}[3mstring\u{1b}[0m\u{1b}[0m"
+        )
+    }
+    #[test]
     fn test_split_colors() {
         let string = String::from("\u{1b}[38;2;255;255;255mstring\u{1b}[0m");
         let split_color = split_colors(&string);
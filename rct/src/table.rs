@@ -1,18 +1,95 @@
-use crate::{row::Row, styles::borders::BorderStyle};
+use crate::cell::Cell;
+use crate::format::Format;
+use crate::styles::align::Align;
 use crate::styles::borders::Border;
-use crate::styles::color::split_colors;
-use std::fmt::{Display, Formatter};
+use crate::terminal;
+use crate::{row::Row, styles::borders::BorderStyle};
+use std::borrow::Cow;
+use std::fmt::{self, Display, Formatter};
+use std::io;
 
 #[derive(Debug, Clone, Default)]
 pub struct Table {
-    header: Option<Row>,
-    rows: Vec<Row>,
-    border: Border,
+    /// Cell displayed above the header, spanning every column.
+    pub(crate) title: Option<Cell>,
+    pub(crate) header: Option<Row>,
+    /// Cells displayed above the header, each one spanning several columns.
+    pub(crate) groups: Vec<(Cell, usize)>,
+    pub(crate) rows: Vec<Row>,
+    /// Cells spanning every column, each one displayed above the row at its index.
+    pub(crate) sections: Vec<(usize, Cell)>,
+    /// Row displayed below the other rows, in its own section.
+    pub(crate) footer: Option<Row>,
+    pub(crate) border: Border,
+    pub(crate) align: Align,
+    /// Settings of the columns, overriding the ones of the table.
+    pub(crate) column_styles: Vec<ColumnStyle>,
+    pub(crate) max_width: Option<usize>,
+    pub(crate) overflow: Overflow,
+    pub(crate) layout: Layout,
+    /// Whether the chunks of a split table are labelled with the columns they display.
+    pub(crate) chunk_label: bool,
+}
+
+/// Settings of a single column, `None` keeping the setting of the table.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ColumnStyle {
+    pub(crate) align: Option<Align>,
+    pub(crate) width: Option<usize>,
+    pub(crate) max_width: Option<usize>,
+    pub(crate) overflow: Option<Overflow>,
+    pub(crate) format: Option<Format>,
+    /// Whether the column is repeated in every chunk of a split table.
+    pub(crate) frozen: bool,
+}
+
+/// What to do with the contents of a cell wider than the max width of the table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// Break the contents on several lines.
+    #[default]
+    Wrap,
+    /// Cut the contents and end them with `…`.
+    Truncate,
+}
+
+/// How the rows of the table are laid out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// A line per row and a column per field.
+    #[default]
+    Horizontal,
+    /// A block per row, with a line per field holding its header and its value.
+    Expanded,
+    /// Expanded when the horizontal table is wider than the given number of columns.
+    Auto(usize),
+    /// Split in stacked tables no wider than the given number of columns when the horizontal
+    /// table is wider, see [`Table::split_columns`].
+    Split(usize),
+}
+
+impl Layout {
+    /// Expanded when the table is wider than the terminal the standard output is displayed on.
+    /// When it is not a terminal, the width is read from the `COLUMNS` environment variable,
+    /// 80 columns being assumed when it is not set.
+    pub fn auto() -> Layout {
+        Layout::Auto(terminal::width())
+    }
+
+    /// Split in stacked tables when the table is wider than the terminal, whose width is read
+    /// like for [`Layout::auto`].
+    pub fn split() -> Layout {
+        Layout::Split(terminal::width())
+    }
 }
 
 impl Display for Table {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.print_lines())
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // the table is displayed without its last line break
+        self.print_lines(&mut TrimEnd {
+            inner: f,
+            pending: false,
+        })
     }
 }
 
@@ -28,9 +105,19 @@ impl Table {
     ///
     pub fn new() -> Table {
         Self {
+            title: None,
             header: None,
+            groups: vec![],
             rows: vec![],
+            sections: vec![],
+            footer: None,
             border: Default::default(),
+            align: Align::Left,
+            column_styles: vec![],
+            max_width: None,
+            overflow: Overflow::Wrap,
+            layout: Layout::Horizontal,
+            chunk_label: false,
         }
     }
 
@@ -90,6 +177,116 @@ impl Table {
         self
     }
 
+    /// Add a footer to the table, displayed below the rows in its own section,
+    /// e.g. a [`Table::summary`] of the rows.
+    ///
+    /// The footer summarizes the rows, like the subtotal rows of [`Table::group_by`], so both
+    /// are only displayed: every export, CSV, TSV, JSON, Markdown and HTML, holds the header
+    /// and the data rows alone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rct::cell::ICell;
+    /// use rct::styles::borders::BorderStyle;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .border(BorderStyle::Simple)
+    ///     .add_header(vec!["Title".cell(), "Price".cell()])
+    ///     .add_row(vec!["Harry Potter".cell(), "14.87".cell()])
+    ///     .add_footer(vec!["Total".cell(), "14.87".cell()]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+--------------+-------+
+    /// | Title        │ Price |
+    /// +--------------+-------+
+    /// | Harry Potter │ 14.87 |
+    /// +==============+=======+
+    /// | Total        │ 14.87 |
+    /// +--------------+-------+"
+    /// );
+    /// ```
+    pub fn add_footer<R: Into<Row>>(&mut self, row: R) -> &mut Table {
+        self.footer = Some(row.into());
+
+        self
+    }
+
+    /// Start a new section of rows, below a cell spanning every column.
+    /// The rows added next belong to the section.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rct::cell::ICell;
+    /// use rct::styles::borders::BorderStyle;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .border(BorderStyle::Simple)
+    ///     .add_header(vec!["ID".cell(), "Title".cell()])
+    ///     .add_section("Fantasy".cell())
+    ///     .add_row(vec![1.cell(), "Harry Potter".cell()])
+    ///     .add_section("Action".cell())
+    ///     .add_row(vec![2.cell(), "Avenger".cell()]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "+----+--------------+
+    /// | ID │ Title        |
+    /// +====+==============+
+    /// | Fantasy           |
+    /// +----+--------------+
+    /// | 1  │ Harry Potter |
+    /// +====+==============+
+    /// | Action            |
+    /// +----+--------------+
+    /// | 2  │ Avenger      |
+    /// +----+--------------+"
+    /// );
+    /// ```
+    pub fn add_section(&mut self, cell: Cell) -> &mut Table {
+        self.sections.push((self.rows.len(), cell));
+
+        self
+    }
+
+    /// Add a row above the header, made of cells spanning the given number of columns.
+    /// The columns left after the last group get an empty cell each.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .add_group_header(vec![("".cell(), 1), ("Customer".cell(), 2)])
+    ///     .add_header(vec!["ID".cell(), "Name".cell(), "Email".cell()])
+    ///     .add_row(vec![1.cell(), "Harry".cell(), "harry@hogwarts.uk".cell()]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "╔════╤═══════════════════════════╗
+    /// ║    │         Customer          ║
+    /// ╟────┼───────┬───────────────────╢
+    /// ║ ID │ Name  │ Email             ║
+    /// ╟────┼───────┼───────────────────╢
+    /// ║ 1  │ Harry │ harry@hogwarts.uk ║
+    /// ╚════╧═══════╧═══════════════════╝"
+    /// );
+    /// ```
+    pub fn add_group_header(&mut self, groups: Vec<(Cell, usize)>) -> &mut Table {
+        self.groups = groups.into_iter().filter(|(_, span)| *span > 0).collect();
+
+        self
+    }
+
     pub fn border(&mut self, border: BorderStyle) -> &mut Table {
         let border = match border {
             BorderStyle::Default => Border::default(),
@@ -101,17 +298,566 @@ impl Table {
         self
     }
 
+    /// Align the contents of every column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// use rct::styles::align::Align;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table.align(Align::Center);
+    /// ```
+    pub fn align(&mut self, align: Align) -> &mut Table {
+        self.align = align;
+
+        self
+    }
+
+    /// Align the contents of the column at `index`, overriding [`Table::align`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// use rct::styles::align::Align;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table.align_column(2, Align::Right);
+    /// ```
+    pub fn align_column(&mut self, index: usize, align: Align) -> &mut Table {
+        self.column_style(index).align = Some(align);
+
+        self
+    }
+
+    /// Set the width of the contents of the column at `index`.
+    /// Narrower cells are padded, wider cells are wrapped or truncated with
+    /// [`Table::overflow_column`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .width_column(1, 8)
+    ///     .add_row(vec![1.cell(), "Harry Potter".cell()]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "╔═══╤══════════╗
+    /// ║ 1 │ Harry    ║
+    /// ║   │ Potter   ║
+    /// ╚═══╧══════════╝"
+    /// );
+    /// ```
+    pub fn width_column(&mut self, index: usize, width: usize) -> &mut Table {
+        self.column_style(index).width = Some(width);
+
+        self
+    }
+
+    /// Limit the width of the contents of the column at `index`, overriding [`Table::max_width`].
+    pub fn max_width_column(&mut self, index: usize, width: usize) -> &mut Table {
+        self.column_style(index).max_width = Some(width);
+
+        self
+    }
+
+    /// Choose how the cells of the column at `index` wider than their column are displayed,
+    /// overriding [`Table::overflow`].
+    pub fn overflow_column(&mut self, index: usize, overflow: Overflow) -> &mut Table {
+        self.column_style(index).overflow = Some(overflow);
+
+        self
+    }
+
+    /// Display the typed values of the column at `index` with `format`, a
+    /// [`NumberFormat`](crate::format::NumberFormat), a [`DurationFormat`](crate::format::DurationFormat)
+    /// or a [`TimeFormat`](crate::format::TimeFormat), see [`Cell::typed`]. The cells whose value
+    /// does not suit the format are displayed as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rct::cell::{Cell, ICell};
+    /// use rct::format::NumberFormat;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .format_column(1, NumberFormat::currency("$"))
+    ///     .add_header(vec!["Title".cell(), "Price".cell()])
+    ///     .add_row(vec!["Harry Potter".cell(), Cell::typed(1499)]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "╔══════════════╤═══════════╗
+    /// ║ Title        │ Price     ║
+    /// ╟──────────────┼───────────╢
+    /// ║ Harry Potter │ $1,499.00 ║
+    /// ╚══════════════╧═══════════╝"
+    /// );
+    /// ```
+    pub fn format_column<F: Into<Format>>(&mut self, index: usize, format: F) -> &mut Table {
+        self.column_style(index).format = Some(format.into());
+
+        self
+    }
+
+    /// Repeat the column at `index` first in every chunk of the table, like a key column,
+    /// when it is split with [`Table::split_columns`] or [`Layout::Split`].
+    pub fn freeze_column(&mut self, index: usize) -> &mut Table {
+        self.column_style(index).frozen = true;
+
+        self
+    }
+
+    /// Add a title above the header, spanning every column. A table without columns is
+    /// displayed as a box holding the title alone.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .title("Movies".cell())
+    ///     .add_header(vec!["ID".cell(), "Title".cell()])
+    ///     .add_row(vec![1.cell(), "Avenger".cell()]);
+    ///
+    /// assert_eq!(
+    ///     table.to_string(),
+    ///     "╔══════════════╗
+    /// ║    Movies    ║
+    /// ╟────┬─────────╢
+    /// ║ ID │ Title   ║
+    /// ╟────┼─────────╢
+    /// ║ 1  │ Avenger ║
+    /// ╚════╧═════════╝"
+    /// );
+    /// ```
+    pub fn title(&mut self, title: Cell) -> &mut Table {
+        self.title = Some(title);
+
+        self
+    }
+
+    /// Limit the width of the contents of every column to `width`.
+    /// Wider cells are wrapped, or truncated with [`Table::overflow`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// use rct::table::{Overflow, Table};
+    /// let mut table = Table::new();
+    ///
+    /// table.max_width(30).overflow(Overflow::Truncate);
+    /// ```
+    pub fn max_width(&mut self, width: usize) -> &mut Table {
+        self.max_width = Some(width);
+
+        self
+    }
+
+    /// Choose how cells wider than [`Table::max_width`] are displayed.
+    pub fn overflow(&mut self, overflow: Overflow) -> &mut Table {
+        self.overflow = overflow;
+
+        self
+    }
+
+    /// Choose how the rows are laid out, see [`Layout`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::styles::borders::BorderStyle;
+    /// use rct::table::{Layout, Table};
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .border(BorderStyle::Simple)
+    ///     .layout(Layout::Expanded)
+    ///     .add_header(vec!["ID".cell(), "Title".cell()])
+    ///     .add_row(vec![1.cell(), "Harry Potter".cell()])
+    ///     .add_row(vec![2.cell(), "Avenger".cell()]);
+    ///
+    /// assert_eq!(
+    ///     "\n".to_string() + &table.to_string(),
+    ///     r#"
+    /// +-[ RECORD 1 ]---------+
+    /// | ID    │ 1            |
+    /// | Title │ Harry Potter |
+    /// +-[ RECORD 2 ]---------+
+    /// | ID    │ 2            |
+    /// | Title │ Avenger      |
+    /// +-------+--------------+"#
+    /// );
+    /// ```
+    pub fn layout(&mut self, layout: Layout) -> &mut Table {
+        self.layout = layout;
+
+        self
+    }
+
+    /// Label every chunk of a split table with the columns it displays, like
+    /// `columns 1–5 of 12`, below the title.
+    pub fn chunk_label(&mut self, label: bool) -> &mut Table {
+        self.chunk_label = label;
+
+        self
+    }
+
+    /// Returns the header of the table.
+    pub fn header(&self) -> Option<&Row> {
+        self.header.as_ref()
+    }
+
+    /// Returns the rows of the table.
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    /// the rows holding data, the subtotals being left out.
+    pub(crate) fn data_rows(&self) -> impl Iterator<Item = &Row> {
+        self.rows.iter().filter(|row| !row.subtotal)
+    }
+
+    /// Returns a new table whose rows are the columns of this one, the header becoming
+    /// the first column and the footer the last one. The title, the borders and the width
+    /// settings of the table are kept, the settings of its columns, its group header and
+    /// its sections are not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::styles::borders::BorderStyle;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .border(BorderStyle::Simple)
+    ///     .add_header(vec!["ID".cell(), "Title".cell()])
+    ///     .add_row(vec![1.cell(), "Harry Potter".cell()])
+    ///     .add_row(vec![2.cell(), "Avenger".cell()]);
+    ///
+    /// assert_eq!(
+    ///     "\n".to_string() + &table.transpose().to_string(),
+    ///     r#"
+    /// +-------+--------------+---------+
+    /// | ID    │ 1            │ 2       |
+    /// +-------+--------------+---------+
+    /// | Title │ Harry Potter │ Avenger |
+    /// +-------+--------------+---------+"#
+    /// );
+    /// ```
+    pub fn transpose(&self) -> Table {
+        let rows = self
+            .header
+            .iter()
+            .chain(self.rows.iter())
+            .chain(self.footer.iter())
+            .collect::<Vec<_>>();
+        let empty = Cell::from_lines(vec![]);
+
+        let mut table = Table {
+            title: self.title.clone(),
+            ..self.clone_settings()
+        };
+        for index in 0..self.columns() {
+            let cells = rows
+                .iter()
+                .map(|row| row.cells.get(index).unwrap_or(&empty).clone())
+                .collect::<Vec<_>>();
+            table.add_row(cells);
+        }
+
+        table
+    }
+
+    /// Returns the index of the first column whose header is `name`, colors being left out.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table.add_header(vec!["ID".cell(), "Title".cell()]);
+    ///
+    /// assert_eq!(table.column_index("Title"), Some(1));
+    /// assert_eq!(table.column_index("Price"), None);
+    /// ```
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.header
+            .as_ref()?
+            .cells
+            .iter()
+            .position(|cell| cell.plain_text() == name)
+    }
+
+    /// Returns a new table made of the columns at `columns`, in that order. A column may be
+    /// repeated, and the columns out of the table are empty. The settings of the selected
+    /// columns and the sections are kept, the group header is not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::styles::borders::BorderStyle;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .border(BorderStyle::Simple)
+    ///     .add_header(vec!["ID".cell(), "Title".cell(), "Price".cell()])
+    ///     .add_row(vec![1.cell(), "Harry Potter".cell(), "14.87".cell()]);
+    ///
+    /// let price = table.column_index("Price").unwrap();
+    /// assert_eq!(
+    ///     "\n".to_string() + &table.select(&[price, 0]).to_string(),
+    ///     r#"
+    /// +-------+----+
+    /// | Price │ ID |
+    /// +-------+----+
+    /// | 14.87 │ 1  |
+    /// +-------+----+"#
+    /// );
+    /// ```
+    pub fn select(&self, columns: &[usize]) -> Table {
+        let empty = Cell::from_lines(vec![]);
+        let project = |row: &Row| -> Row {
+            let cells = columns
+                .iter()
+                .map(|index| row.cells.get(*index).unwrap_or(&empty).clone());
+            Row {
+                cells: cells.collect(),
+                subtotal: row.subtotal,
+            }
+        };
+
+        Table {
+            title: self.title.clone(),
+            header: self.header.as_ref().map(project),
+            groups: vec![],
+            rows: self.rows.iter().map(project).collect(),
+            sections: self.sections.clone(),
+            footer: self.footer.as_ref().map(project),
+            column_styles: columns
+                .iter()
+                .map(|index| self.column_styles.get(*index).cloned().unwrap_or_default())
+                .collect(),
+            ..self.clone_settings()
+        }
+    }
+
+    /// Returns a new table with the rows for which `predicate` returns `true`, given the texts
+    /// of their cells without colors. The header, the footer and the settings of the table
+    /// are kept, the sections and the subtotal rows are not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table
+    ///     .add_header(vec!["ID".cell(), "Price".cell()])
+    ///     .add_row(vec![1.cell(), "14.87".cell()])
+    ///     .add_row(vec![2.cell(), "18.80".cell()]);
+    ///
+    /// let price = table.column_index("Price").unwrap();
+    /// let expensive = table.filter(|values| values[price].parse::<f32>().unwrap() > 15.0);
+    /// assert_eq!(expensive.rows().len(), 1);
+    /// assert_eq!(expensive.rows()[0].cells[0].to_string(), "2");
+    /// ```
+    pub fn filter<P>(&self, mut predicate: P) -> Table
+    where
+        P: FnMut(&[String]) -> bool,
+    {
+        let columns = self.columns();
+        let rows = self
+            .data_rows()
+            .filter(|row| {
+                let mut values = row.cells.iter().map(Cell::plain_text).collect::<Vec<_>>();
+                // short rows are given empty values, to index every column
+                values.resize(columns, String::new());
+                predicate(&values)
+            })
+            .cloned()
+            .collect();
+
+        Table {
+            title: self.title.clone(),
+            header: self.header.clone(),
+            groups: self.groups.clone(),
+            rows,
+            footer: self.footer.clone(),
+            column_styles: self.column_styles.clone(),
+            ..self.clone_settings()
+        }
+    }
+
+    /// Returns an empty table with the settings of this one.
+    pub(crate) fn clone_settings(&self) -> Table {
+        Table {
+            border: self.border.clone(),
+            align: self.align,
+            max_width: self.max_width,
+            overflow: self.overflow,
+            layout: self.layout,
+            chunk_label: self.chunk_label,
+            ..Table::new()
+        }
+    }
+
+    /// Returns the number of columns of the table.
+    pub(crate) fn columns(&self) -> usize {
+        self.header
+            .iter()
+            .chain(self.rows.iter())
+            .chain(self.footer.iter())
+            .map(|row| row.cells.len())
+            .chain(std::iter::once(
+                self.groups.iter().map(|(_, span)| span).sum(),
+            ))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of columns spanned by every cell above the header,
+    /// the columns left after the last group being spanned one by one.
+    pub(crate) fn group_spans(&self) -> Vec<usize> {
+        let mut spans: Vec<usize> = self.groups.iter().map(|(_, span)| *span).collect();
+        let spanned: usize = spans.iter().sum();
+        spans.resize(spans.len() + self.columns().saturating_sub(spanned), 1);
+
+        spans
+    }
+
+    /// Returns the settings of the column at `index`, to change them.
+    fn column_style(&mut self, index: usize) -> &mut ColumnStyle {
+        if self.column_styles.len() <= index {
+            self.column_styles.resize(index + 1, ColumnStyle::default());
+        }
+
+        &mut self.column_styles[index]
+    }
+
+    /// Returns the alignment of the column at `index`.
+    pub(crate) fn column_align(&self, index: usize) -> Align {
+        self.column_styles
+            .get(index)
+            .and_then(|style| style.align)
+            .unwrap_or(self.align)
+    }
+
+    /// Returns the max width of the contents of the column at `index`, if any.
+    pub(crate) fn column_max_width(&self, index: usize) -> Option<usize> {
+        let style = self.column_styles.get(index);
+        style
+            .and_then(|style| style.width.or(style.max_width))
+            .or(self.max_width)
+    }
+
+    /// Returns how the cells of the column at `index` wider than their column are displayed.
+    pub(crate) fn column_overflow(&self, index: usize) -> Overflow {
+        self.column_styles
+            .get(index)
+            .and_then(|style| style.overflow)
+            .unwrap_or(self.overflow)
+    }
+
+    /// Returns the cell of the column at `index` with the format of the column.
+    pub(crate) fn format_cell<'a>(&self, index: usize, cell: &'a Cell) -> Cow<'a, Cell> {
+        let format = self
+            .column_styles
+            .get(index)
+            .and_then(|style| style.format.as_ref());
+        match format.and_then(|format| format.apply(cell)) {
+            Some(cell) => Cow::Owned(cell),
+            None => Cow::Borrowed(cell),
+        }
+    }
+
+    /// Returns the row with every cell formatted and fitted in the max width of its column.
+    pub(crate) fn fit<'a>(&self, row: &'a Row) -> Cow<'a, Row> {
+        let fitted = |index: usize, cell: &'a Cell| {
+            let cell = self.format_cell(index, cell);
+            match self.column_max_width(index) {
+                Some(width) if cell.width > width => match self.column_overflow(index) {
+                    Overflow::Wrap => Cow::Owned(cell.wrap(width)),
+                    Overflow::Truncate => Cow::Owned(cell.truncate(width)),
+                },
+                _ => cell,
+            }
+        };
+        let cells = row
+            .cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| fitted(index, cell))
+            .collect::<Vec<_>>();
+        if cells.iter().all(|cell| matches!(cell, Cow::Borrowed(_))) {
+            return Cow::Borrowed(row);
+        }
+
+        let cells = cells.into_iter().map(Cow::into_owned).collect::<Vec<_>>();
+        Cow::Owned(cells.into())
+    }
+
     /// Returns the vec of max columns length for the table.
-    fn set_max_width(&self) -> Vec<usize> {
+    pub(crate) fn set_max_width(&self) -> Vec<usize> {
         // create a new vec of zero with size equal to number of columns
-        let mut column_len: Vec<usize> = vec![0; self.rows[0].cells.len()];
+        let mut column_len: Vec<usize> = vec![0; self.columns()];
 
         // set values of vec with the max column length
-        if let Some(header) = &self.header {
-            max_column_length(&mut column_len, header);
+        let rows = self.header.iter().chain(self.rows.iter());
+        for row in rows.chain(self.footer.iter()) {
+            max_column_length(&mut column_len, &self.fit(row));
+        }
+
+        // columns with a fixed width are padded to it
+        for (len, style) in column_len.iter_mut().zip(&self.column_styles) {
+            if let Some(width) = style.width {
+                *len = width + 2;
+            }
         }
-        for row in self.rows.iter() {
-            max_column_length(&mut column_len, row);
+
+        // widen the last column of a group too narrow for its cell
+        let mut start = 0;
+        for (cell, span) in &self.groups {
+            let end = start + span;
+            let width = column_len[start..end].iter().sum::<usize>() + span - 1;
+            if cell.width + 2 > width {
+                column_len[end - 1] += cell.width + 2 - width;
+            }
+            start = end;
+        }
+
+        // widen the last column when the title or a section is wider than the table
+        let spanning = self
+            .title
+            .iter()
+            .chain(self.sections.iter().map(|(_, cell)| cell));
+        if let Some(last) = column_len.len().checked_sub(1) {
+            for cell in spanning {
+                let width = column_len.iter().sum::<usize>() + last;
+                if cell.width + 2 > width {
+                    column_len[last] += cell.width + 2 - width;
+                }
+            }
         }
 
         column_len
@@ -120,79 +866,310 @@ impl Table {
     /// print the top header with the given border or default of table like this:
     /// ╔════════╤═══════════╗
     ///
-    fn print_header(&self, column_len: &[usize]) -> String {
-        let mut view = self.border.top_left.to_string();
-        // make an iterator to generate the border top and join this with border top middle
-        let header: Vec<String> = column_len
-            .iter()
-            .map(|col| self.border.top.to_string().repeat(*col))
-            .collect();
-        view += &header.join(&self.border.top_mid.to_string());
-        view += &self.border.top_right.to_string();
-        view += "\n";
-
-        view
+    pub(crate) fn print_header<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        column_len: &[usize],
+    ) -> fmt::Result {
+        let border = &self.border;
+        self.print_border(
+            f,
+            column_len,
+            [
+                border.top_left,
+                border.top,
+                border.top_mid,
+                border.top_right,
+            ],
+        )
     }
 
     /// print the bottom with the given border or default of table like this:
     /// ╚════════╧════════════╝
-    fn print_bottom(&self, column_len: &[usize]) -> String {
-        let mut view: String = self.border.bottom_left.to_string();
-        // make an iterator to generate the border bottom and join this with border bottom middle
-        let bottom: Vec<String> = column_len
-            .iter()
-            .map(|col| self.border.bottom.to_string().repeat(*col))
-            .collect();
-        view += &bottom.join(&self.border.bottom_mid.to_string());
-        view += &self.border.bottom_right.to_string();
-
-        view
+    pub(crate) fn print_bottom<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        column_len: &[usize],
+    ) -> fmt::Result {
+        let border = &self.border;
+        self.print_border(
+            f,
+            column_len,
+            [
+                border.bottom_left,
+                border.bottom,
+                border.bottom_mid,
+                border.bottom_right,
+            ],
+        )
     }
 
     /// print the middle (jointures between two rows) of table.
     /// ╟────────┼──────────╢
-    fn print_table_middle(&self, column_len: &[usize]) -> String {
-        let mut view: String = self.border.left_mid.to_string();
-        // make an iterator to generate the border middle
-        let middle: Vec<String> = column_len
+    pub(crate) fn print_table_middle<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        column_len: &[usize],
+    ) -> fmt::Result {
+        let border = &self.border;
+        self.print_border(
+            f,
+            column_len,
+            [
+                border.left_mid,
+                border.mid,
+                border.mid_mid,
+                border.right_mid,
+            ],
+        )
+    }
+
+    /// print the line between two rows, a `section` line starting a section, the junctions
+    /// depending on whether the rows `above` and `below` are split in columns.
+    /// ╠════╧═════════╣
+    /// ║ Fantasy      ║
+    /// ╟────┬─────────╢
+    fn print_separator<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        column_len: &[usize],
+        section: bool,
+        above: bool,
+        below: bool,
+    ) -> fmt::Result {
+        let border = &self.border;
+        let [left, line, right] = match section {
+            true => [border.left_section, border.section, border.right_section],
+            false => [border.left_mid, border.mid, border.right_mid],
+        };
+        let junction = match (above, below) {
+            (true, true) if section => border.section_mid,
+            (true, true) => border.mid_mid,
+            (true, false) => border.section_bottom,
+            (false, true) => border.mid_top,
+            (false, false) => line,
+        };
+
+        self.print_border(f, column_len, [left, line, junction, right])
+    }
+
+    /// print an horizontal border line made of `[left, line, junction, right]` characters.
+    fn print_border<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        column_len: &[usize],
+        chars: [char; 4],
+    ) -> fmt::Result {
+        self.print_group_border(f, column_len, &[], chars, chars[2])
+    }
+
+    /// print an horizontal border line around the group header, where the junctions inside
+    /// a group are made of the `inner` character.
+    /// ╔════════╤════════════════════╗
+    /// ╟────────┼─────────┬──────────╢
+    pub(crate) fn print_group_border<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        column_len: &[usize],
+        spans: &[usize],
+        chars: [char; 4],
+        inner: char,
+    ) -> fmt::Result {
+        let [left, line, junction, right] = chars;
+        let mut boundaries = spans.iter().scan(0, |start, span| {
+            *start += span;
+            Some(*start)
+        });
+        let mut next = boundaries.next();
+
+        f.write_char(left)?;
+        for (index, col) in column_len.iter().enumerate() {
+            if index > 0 {
+                match next {
+                    Some(boundary) if boundary > index => f.write_char(inner)?,
+                    _ => {
+                        next = boundaries.next();
+                        f.write_char(junction)?;
+                    }
+                }
+            }
+            repeat(f, line, *col)?;
+        }
+        f.write_char(right)?;
+        f.write_char('\n')
+    }
+
+    /// print the top of the table with the title and the group header, if any.
+    /// ╔═════════════════════════════╗
+    /// ║            Title            ║
+    /// ╟────────┬────────────────────╢
+    /// ║        │       Group        ║
+    /// ╟────────┼─────────┬──────────╢
+    pub(crate) fn print_top<W: fmt::Write>(&self, f: &mut W, column_len: &[usize]) -> fmt::Result {
+        let border = &self.border;
+        let spans = self.group_spans();
+        let title = self.title.as_ref().filter(|_| !column_len.is_empty());
+        // a section starting the table spans every column, like the title
+        let columns_below = self.header.is_some()
+            || !self.groups.is_empty()
+            || !matches!(self.sections.first(), Some((0, _)));
+
+        if let Some(title) = title {
+            let total = column_len.iter().sum::<usize>() + column_len.len() - 1;
+            let top = [
+                border.top_left,
+                border.top,
+                border.top_mid,
+                border.top_right,
+            ];
+            self.print_group_border(f, column_len, &[column_len.len()], top, border.top)?;
+            self.print_cells(f, &Row::from(vec![title.clone()]), &[total], |_| {
+                Align::Center
+            })?;
+            // nothing below the title, the bottom border follows
+            if self.columns() == 0 {
+                return Ok(());
+            }
+            // below the title, the junctions are the borders of the groups, or of the columns
+            let spans = match (self.groups.is_empty(), columns_below) {
+                (false, _) => spans.clone(),
+                (true, true) => vec![1; column_len.len()],
+                (true, false) => vec![column_len.len()],
+            };
+            let middle = [
+                border.left_mid,
+                border.mid,
+                border.mid_top,
+                border.right_mid,
+            ];
+            self.print_group_border(f, column_len, &spans, middle, border.mid)?;
+        } else if self.groups.is_empty() && columns_below {
+            return self.print_header(f, column_len);
+        } else if self.groups.is_empty() {
+            let top = [
+                border.top_left,
+                border.top,
+                border.top_mid,
+                border.top_right,
+            ];
+            return self.print_group_border(f, column_len, &[column_len.len()], top, border.top);
+        }
+
+        if self.groups.is_empty() {
+            return Ok(());
+        }
+
+        if title.is_none() {
+            self.print_group_border(
+                f,
+                column_len,
+                &spans,
+                [
+                    border.top_left,
+                    border.top,
+                    border.top_mid,
+                    border.top_right,
+                ],
+                border.top,
+            )?;
+        }
+
+        let mut cells = self
+            .groups
             .iter()
-            .map(|col| self.border.mid.to_string().repeat(*col))
-            .collect();
-        view += &middle.join(&self.border.mid_mid.to_string());
-        view += &self.border.right_mid.to_string();
+            .map(|(cell, _)| cell.clone())
+            .collect::<Vec<_>>();
+        cells.resize(spans.len(), Cell::from_lines(vec![]));
+        let mut start = 0;
+        let widths = spans
+            .iter()
+            .map(|span| {
+                let width = column_len[start..start + span].iter().sum::<usize>() + span - 1;
+                start += span;
+                width
+            })
+            .collect::<Vec<_>>();
+        self.print_cells(f, &Row::from(cells), &widths, |_| Align::Center)?;
 
-        view
+        self.print_group_border(
+            f,
+            column_len,
+            &spans,
+            [
+                border.left_mid,
+                border.mid,
+                border.mid_mid,
+                border.right_mid,
+            ],
+            border.mid_top,
+        )
     }
 
     /// print every rows and header of table.
-    fn print_lines(&self) -> String {
-        let mut view = String::new();
-        let mut contents = vec![];
+    fn print_lines<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
         let width_column = self.set_max_width();
+        let too_wide =
+            |width: usize| width_column.iter().sum::<usize>() + width_column.len() + 1 > width;
+        let expanded = match self.layout {
+            Layout::Horizontal | Layout::Split(_) => false,
+            Layout::Expanded => true,
+            Layout::Auto(width) => too_wide(width),
+        };
+        // a table without rows has nothing to expand
+        if expanded && !self.rows.is_empty() {
+            return self.print_expanded(f);
+        }
+        if let Layout::Split(width) = self.layout {
+            if too_wide(width) {
+                return self.print_split(f, width);
+            }
+        }
 
-        if let Some(header) = self.header.as_ref() {
-            contents.push(self.print_line(header, &width_column));
+        // a table without columns still displays its title, in a column of its width
+        let width_column = match (&self.title, width_column.is_empty()) {
+            (Some(title), true) => vec![title.width + 2],
+            _ => width_column,
+        };
+        self.print_top(f, &width_column)?;
+        let total = width_column.iter().sum::<usize>() + width_column.len().saturating_sub(1);
+        // whether a line was printed below the top, and if it is split in columns
+        let mut above = None;
+        if let Some(header) = &self.header {
+            self.print_line(f, &self.fit(header), &width_column)?;
+            above = Some(true);
         }
 
-        for row in self.rows.iter() {
-            contents.push(self.print_line(row, &width_column));
+        let mut sections = self.sections.iter().peekable();
+        for (index, row) in self.rows.iter().enumerate() {
+            while let Some((_, cell)) = sections.next_if(|(start, _)| *start == index) {
+                if let Some(columns) = above {
+                    self.print_separator(f, &width_column, true, columns, false)?;
+                }
+                self.print_cells(f, &Row::from(vec![cell.clone()]), &[total], |_| Align::Left)?;
+                above = Some(false);
+            }
+            if let Some(columns) = above {
+                self.print_separator(f, &width_column, false, columns, true)?;
+            }
+            self.print_line(f, &self.fit(row), &width_column)?;
+            above = Some(true);
         }
 
-        view += &self.print_header(&width_column);
-        for (index, content) in contents.iter().enumerate() {
-            view += &self.draw(content, &width_column, (contents.len() - 1) == index);
+        if let Some(footer) = &self.footer {
+            if let Some(columns) = above {
+                self.print_separator(f, &width_column, true, columns, true)?;
+            }
+            self.print_line(f, &self.fit(footer), &width_column)?;
         }
-        view += &self.print_bottom(&width_column);
-        view
+        self.print_bottom(f, &width_column)
     }
 
-    /// Given a vector of cells, where each cell contains a vector of strings,
-    /// returns a vector of vectors, where each inner vector represents a row of the output.
-    /// The output format is a matrix where each value is padded with spaces to match the desired column width.
+    /// Writes the lines of a row, where each value is padded with spaces to match the desired
+    /// column width and separated by the border.
     ///
     /// # Arguments
     ///
-    /// *`cells` - A vector of `Cell` structures, where each cell contains a vector of strings.
+    /// *`row` - A row of `Cell` structures, where each cell contains a vector of strings.
     /// *`width_column` - A slice of integers representing the desired width of each column.
     ///
     /// # Example
@@ -205,90 +1182,111 @@ impl Table {
     ///
     /// let width_column = vec![3, 3, 3];
     ///
-    /// let output = print_line(&cells, &width_column);
+    /// print_line(f, &cells.into(), &width_column);
     ///
-    /// assert_eq!(output, vec![
-    /// vec![" a ", " b ", " e "],
-    /// vec![" ", " c ", " "],
-    /// vec![" ", " d ", " "]
-    /// ]);
+    /// ║ a │ b │ e ║
+    /// ║   │ c │   ║
+    /// ║   │ d │   ║
     ///
-    fn print_line(&self, row: &Row, width_column: &[usize]) -> Vec<Vec<String>> {
-        // Collect the cell contents into a vector of vectors, adding spaces around each value
-        // Example input:
-        //   ["a", "b,c,d", "e"] --> [[" a "], [" b ", " c ", " d "], [" e "]]
-        let content = row
+    pub(crate) fn print_line<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        row: &Row,
+        width_column: &[usize],
+    ) -> fmt::Result {
+        self.print_cells(f, row, width_column, |index| self.column_align(index))
+    }
+
+    /// Writes the lines of a row, the cell at `index` being aligned with `align(index)`.
+    pub(crate) fn print_cells<W: fmt::Write>(
+        &self,
+        f: &mut W,
+        row: &Row,
+        width_column: &[usize],
+        align: impl Fn(usize) -> Align,
+    ) -> fmt::Result {
+        // Get the maximum number of lines in any cell
+        let max_column = row
             .cells
             .iter()
-            .map(|cell| {
-                cell.data
-                    .iter()
-                    .map(|data| format!(" {} ", data))
-                    .collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>();
+            .map(|cell| cell.data.len())
+            .max()
+            .unwrap_or(0)
+            .max(1);
 
-        // Get the maximum number of cells in any column
-        // Example input:
-        //   [[" a "], [" b ", " c ", " d "], [" e "]] --> max_column = 3
-        let max_column = content.iter().map(|c| c.len()).max().unwrap_or(0);
-
-        // Build a vector of vectors, with each inner vector representing a row of the output
-        // Example output:
-        //   [[" a ", " b ", " e "], ["   ", " c ", "   "], ["   ", " d ", "   "]]
-        (0..max_column)
-            .map(|i| {
-                content
-                    .iter()
-                    .enumerate()
-                    .fold(vec![], |mut acc, (index, col)| {
-                        let value = col.get(i).unwrap_or(&String::new()).to_owned();
-                        let width = *width_column.get(index).unwrap_or(&0);
-                        // Add padding to the cell value to match the desired column width
-                        let padding =
-                            " ".repeat(width.saturating_sub(split_colors(&value).chars().count()));
-                        acc.push(format!("{}{}", value, padding));
-                        acc
-                    })
-            })
-            .collect::<Vec<_>>()
-    }
-
-    /// Create all of the lines in rows with the border.
-    fn draw(&self, rows: &Vec<Vec<String>>, width_column: &[usize], last_row: bool) -> String {
-        let mut view = String::new();
-        // We transform this vector:
-        // [["string1"], ["string2"], ["", "string3", ""] ["string4"]]
-        // to:
-        // [║"string1"│, "string2"│, "string4║]
-        // ╟──────┼──────────┼────────────╢
-        // [║"      "│, "string3"│, "      "║]
-        let mut lines = vec![];
-        for (i, row) in rows.iter().enumerate() {
-            let mut view = String::new();
-            for (index, line) in row.iter().enumerate() {
+        for i in 0..max_column {
+            for (index, width) in width_column.iter().enumerate() {
                 if index == 0 {
-                    view += &self.border.left.to_string();
+                    f.write_char(self.border.left)?;
                 } else {
-                    view += &self.border.middle.to_string();
+                    f.write_char(self.border.middle)?;
                 }
-                view += line;
+
+                let (value, value_width) = row
+                    .cells
+                    .get(index)
+                    .and_then(|cell| Some((cell.data.get(i)?.as_str(), cell.line_width(i))))
+                    .unwrap_or_default();
+                // Add padding to the cell value to match the desired column width
+                let padding = width.saturating_sub(value_width + 2);
+                let (left, right) = align(index).split(padding);
+                repeat(f, ' ', left + 1)?;
+                f.write_str(value)?;
+                repeat(f, ' ', right + 1)?;
             }
-            view += &self.border.right.to_string();
-            lines.push(view);
-            // do not add separators if it is the last line
-            if rows.len() - 1 == i && !last_row {
-                lines.push(self.print_table_middle(width_column));
+            if width_column.is_empty() {
+                f.write_char(self.border.left)?;
             }
+            f.write_char(self.border.right)?;
+            f.write_char('\n')?;
         }
-        view += &lines.join("\n");
-        view += "\n";
 
-        view
+        Ok(())
+    }
+
+    /// Writes the table in any [`fmt::Write`], line by line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    /// table.add_row(vec![1.cell(), "Harry Potter".cell()]);
+    ///
+    /// let mut view = String::new();
+    /// table.fmt_to(&mut view).unwrap();
+    /// assert_eq!(view, table.to_string() + "\n");
+    /// ```
+    pub fn fmt_to<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+        self.print_lines(f)
+    }
+
+    /// Writes the table in any [`io::Write`], line by line, without building it in memory.
+    /// Wrap unbuffered writers such as files in a [`io::BufWriter`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    /// table.add_row(vec![1.cell(), "Harry Potter".cell()]);
+    ///
+    /// let mut view = vec![];
+    /// table.write_to(&mut view).unwrap();
+    /// assert_eq!(String::from_utf8(view).unwrap(), table.to_string() + "\n");
+    /// ```
+    pub fn write_to<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        write_io(writer, |f| self.print_lines(f))
     }
 
     /// Display the table on terminal.
     ///
+    /// # Errors
+    ///
+    /// Returns the error met while writing on the standard output, for instance when it is closed.
+    ///
     /// # Examples
     ///
     /// ```rust, no_run
@@ -307,10 +1305,10 @@ impl Table {
     ///         "2001-12-05 22:05:20".cell(),
     ///     ]);
     ///
-    /// table.view();
+    /// table.view().unwrap();
     /// ```
-    pub fn view(&self) {
-        println!("{}", self.print_lines());
+    pub fn view(&self) -> io::Result<()> {
+        self.write_to(&mut io::stdout().lock())
     }
 }
 
@@ -319,8 +1317,70 @@ fn max_column_length(column_len: &mut [usize], row: &Row) {
     let rows: Vec<_> = row.width();
     for (index, row) in rows.iter().enumerate() {
         let current_max = column_len.get(index).unwrap_or(&0);
-        if *row > *current_max {
+        if *row + 2 > *current_max {
             column_len[index] = *row + 2;
         }
     }
 }
+
+/// Writes `count` times the character `c`.
+pub(crate) fn repeat<W: fmt::Write>(f: &mut W, c: char, count: usize) -> fmt::Result {
+    for _ in 0..count {
+        f.write_char(c)?;
+    }
+
+    Ok(())
+}
+
+/// Runs `print` on an [`io::Write`], returning the I/O error that stopped it.
+pub(crate) fn write_io<W, F>(writer: &mut W, print: F) -> io::Result<()>
+where
+    W: io::Write,
+    F: FnOnce(&mut IoWriter<W>) -> fmt::Result,
+{
+    let mut adapter = IoWriter {
+        inner: writer,
+        error: Ok(()),
+    };
+    match print(&mut adapter) {
+        Ok(()) => Ok(()),
+        Err(_) => match adapter.error {
+            Err(err) => Err(err),
+            Ok(()) => Err(io::Error::other("formatter error")),
+        },
+    }
+}
+
+/// Adapts an [`io::Write`] to [`fmt::Write`], keeping the I/O error that stopped the writing.
+pub(crate) struct IoWriter<'a, W: io::Write> {
+    inner: &'a mut W,
+    error: io::Result<()>,
+}
+
+impl<W: io::Write> fmt::Write for IoWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|err| {
+            self.error = Err(err);
+            fmt::Error
+        })
+    }
+}
+
+/// Writes everything but the last line break.
+struct TrimEnd<'a, W: fmt::Write> {
+    inner: &'a mut W,
+    pending: bool,
+}
+
+impl<W: fmt::Write> fmt::Write for TrimEnd<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+        if self.pending {
+            self.inner.write_char('\n')?;
+        }
+        self.pending = s.ends_with('\n');
+        self.inner.write_str(s.strip_suffix('\n').unwrap_or(s))
+    }
+}
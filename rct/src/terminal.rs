@@ -0,0 +1,26 @@
+//! Size of the terminal the standard output is displayed on.
+use terminal_size::{terminal_size, Height, Width};
+
+/// Returns the number of columns of the terminal. When the standard output is not a terminal,
+/// it is read from the `COLUMNS` environment variable, 80 columns being assumed when it is
+/// not set either.
+pub(crate) fn width() -> usize {
+    match terminal_size() {
+        Some((Width(width), _)) if width > 0 => width as usize,
+        _ => from_env("COLUMNS").unwrap_or(80),
+    }
+}
+
+/// Returns the number of lines of the terminal. When the standard output is not a terminal,
+/// it is read from the `LINES` environment variable, 24 lines being assumed when it is
+/// not set either.
+pub(crate) fn height() -> usize {
+    match terminal_size() {
+        Some((_, Height(height))) if height > 0 => height as usize,
+        _ => from_env("LINES").unwrap_or(24),
+    }
+}
+
+fn from_env(name: &str) -> Option<usize> {
+    std::env::var(name).ok()?.trim().parse().ok()
+}
@@ -0,0 +1,256 @@
+//! Helpers measuring and reshaping lines that may contain ANSI escape sequences.
+//!
+//! Every measurement goes through [`display_width`], which counts the columns taken
+//! by each grapheme cluster on a terminal: wide CJK characters and emoji sequences
+//! take two columns, combining marks and escape sequences none.
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// A piece of a line: either a grapheme cluster or an escape sequence.
+enum Piece<'a> {
+    Escape(&'a str),
+    Text(&'a str),
+}
+
+impl Piece<'_> {
+    fn width(&self) -> usize {
+        match self {
+            Piece::Text(grapheme) => grapheme.width(),
+            Piece::Escape(_) => 0,
+        }
+    }
+
+    fn is_whitespace(&self) -> bool {
+        matches!(self, Piece::Text(grapheme) if grapheme.chars().all(char::is_whitespace))
+    }
+}
+
+/// Splits a line in grapheme clusters and ANSI escape sequences.
+fn pieces(line: &str) -> Vec<Piece<'_>> {
+    let mut pieces = vec![];
+    let mut rest = line;
+
+    while let Some(start) = rest.find('\x1b') {
+        pieces.extend(rest[..start].graphemes(true).map(Piece::Text));
+
+        // CSI sequences (`ESC [ ... final`) end with a byte in the range `@` to `~`,
+        // any other escape is made of `ESC` and the next character.
+        let escape = &rest[start..];
+        let mut chars = escape.char_indices().skip(1);
+        let end = match chars.next() {
+            Some((_, '[')) => chars
+                .find(|(_, c)| ('@'..='~').contains(c))
+                .map(|(index, c)| index + c.len_utf8())
+                .unwrap_or(escape.len()),
+            Some((index, c)) => index + c.len_utf8(),
+            None => escape.len(),
+        };
+        pieces.push(Piece::Escape(&escape[..end]));
+        rest = &escape[end..];
+    }
+    pieces.extend(rest.graphemes(true).map(Piece::Text));
+
+    pieces
+}
+
+/// Returns the number of columns needed to display the line on a terminal.
+pub(crate) fn display_width(line: &str) -> usize {
+    // fast path for the common case of plain ASCII text
+    if line.bytes().all(|byte| (0x20..0x7f).contains(&byte)) {
+        return line.len();
+    }
+
+    pieces(line).iter().map(Piece::width).sum()
+}
+
+/// Keeps track of the SGR sequences (colors, fonts) applied at a given point of a line.
+#[derive(Default, Clone)]
+struct Styles(String);
+
+impl Styles {
+    fn apply(&mut self, escape: &str) {
+        if escape == "\x1b[0m" || escape == "\x1b[m" {
+            self.0.clear();
+        } else if escape.starts_with("\x1b[") && escape.ends_with('m') {
+            self.0.push_str(escape);
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        !self.0.is_empty()
+    }
+}
+
+/// Renders a chunk of pieces, reopening the styles active before it and closing
+/// the styles still active at its end.
+fn render(chunk: &[Piece], styles: &mut Styles) -> String {
+    let mut line = styles.0.clone();
+    for piece in chunk {
+        match piece {
+            Piece::Text(grapheme) => line.push_str(grapheme),
+            Piece::Escape(escape) => {
+                styles.apply(escape);
+                line.push_str(escape);
+            }
+        }
+    }
+    if styles.is_active() {
+        line.push_str("\x1b[0m");
+    }
+
+    line
+}
+
+/// Splits a line in several lines no wider than `width` columns.
+///
+/// Lines are broken on the last whitespace that fits, or in the middle of a word
+/// when it is wider than `width`. Colors are closed at the end of every line and
+/// reopened at the start of the next one.
+pub(crate) fn wrap(line: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    if display_width(line) <= width {
+        return vec![line.to_string()];
+    }
+
+    let mut lines = vec![];
+    let mut styles = Styles::default();
+    let mut chunk: Vec<Piece> = vec![];
+    let mut chunk_width = 0;
+
+    for piece in pieces(line) {
+        let piece_width = piece.width();
+
+        if chunk_width + piece_width > width && chunk_width > 0 {
+            // the line is broken on the whitespace itself
+            if piece.is_whitespace() {
+                lines.push(render(&chunk, &mut styles));
+                chunk = vec![];
+                chunk_width = 0;
+                continue;
+            }
+
+            let space = chunk.iter().rposition(Piece::is_whitespace);
+            let rest = match space {
+                Some(index) if index > 0 => {
+                    let rest = chunk.split_off(index + 1);
+                    chunk.pop();
+                    rest
+                }
+                _ => vec![],
+            };
+            lines.push(render(&chunk, &mut styles));
+            chunk = rest;
+            chunk_width = chunk.iter().map(Piece::width).sum();
+        }
+
+        chunk_width += piece_width;
+        chunk.push(piece);
+    }
+    lines.push(render(&chunk, &mut styles));
+
+    lines
+}
+
+/// Cuts a line wider than `width` columns and marks the cut with `…`.
+pub(crate) fn truncate(line: &str, width: usize) -> String {
+    if display_width(line) <= width {
+        return line.to_string();
+    }
+
+    let mut truncated = String::new();
+    let mut used = 0;
+    let mut cut = false;
+    for piece in pieces(line) {
+        match piece {
+            // escape sequences are kept so that colors are still reset
+            Piece::Escape(escape) => truncated.push_str(escape),
+            Piece::Text(grapheme) if !cut => {
+                let grapheme_width = grapheme.width();
+                if used + grapheme_width + 1 > width {
+                    if width > 0 {
+                        truncated.push('…');
+                    }
+                    cut = true;
+                } else {
+                    used += grapheme_width;
+                    truncated.push_str(grapheme);
+                }
+            }
+            Piece::Text(_) => {}
+        }
+    }
+
+    truncated
+}
+
+/// Replaces the text of a line with `text`, keeping its escape sequences: the ones found
+/// before the text are written before `text`, the others after it.
+pub(crate) fn replace_text(line: &str, text: &str) -> String {
+    let mut replaced = String::new();
+    let mut written = false;
+    for piece in pieces(line) {
+        match piece {
+            Piece::Escape(escape) => replaced.push_str(escape),
+            Piece::Text(_) if !written => {
+                replaced.push_str(text);
+                written = true;
+            }
+            Piece::Text(_) => {}
+        }
+    }
+    if !written {
+        replaced.push_str(text);
+    }
+
+    replaced
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::{display_width, replace_text, truncate, wrap};
+
+    #[test]
+    fn test_display_width_ignores_colors() {
+        assert_eq!(display_width("\u{1b}[38;2;255;0;0mstring\u{1b}[0m"), 6);
+    }
+
+    #[test]
+    fn test_wrap_on_whitespace() {
+        assert_eq!(
+            wrap("Spider-man, No Way Home.", 12),
+            vec!["Spider-man,", "No Way Home."]
+        );
+    }
+
+    #[test]
+    fn test_wrap_long_word() {
+        assert_eq!(wrap("abcdefgh", 3), vec!["abc", "def", "gh"]);
+    }
+
+    #[test]
+    fn test_wrap_keeps_colors() {
+        assert_eq!(
+            wrap("\u{1b}[1mab cd\u{1b}[0m", 2),
+            vec!["\u{1b}[1mab\u{1b}[0m", "\u{1b}[1mcd\u{1b}[0m"]
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("Harry Potter", 6), "Harry…");
+        assert_eq!(
+            truncate("\u{1b}[1mHarry\u{1b}[0m", 3),
+            "\u{1b}[1mHa…\u{1b}[0m"
+        );
+        assert_eq!(truncate("Harry", 5), "Harry");
+    }
+
+    #[test]
+    fn test_replace_text_keeps_escapes() {
+        assert_eq!(
+            replace_text("\u{1b}[38;2;255;0;0m2\u{1b}[0m", "$2.00"),
+            "\u{1b}[38;2;255;0;0m$2.00\u{1b}[0m"
+        );
+        assert_eq!(replace_text("2", "$2.00"), "$2.00");
+    }
+}
@@ -0,0 +1,132 @@
+use crate::cell::ICell;
+use crate::row::Row;
+use crate::table::Table;
+
+/// A value displayed as a row of a table, implemented by `#[derive(ToTable)]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use rct::{ICell, IntoTable, Row, ToTable};
+///
+/// struct Movie {
+///     id: u32,
+///     title: &'static str,
+/// }
+///
+/// impl ToTable for Movie {
+///     fn headers() -> Vec<String> {
+///         vec!["ID".to_string(), "Title".to_string()]
+///     }
+///
+///     fn to_row(&self) -> Row {
+///         vec![self.id.cell(), self.title.cell()].into()
+///     }
+/// }
+///
+/// let movies = vec![Movie { id: 1, title: "Avenger" }];
+/// let table = movies.iter().to_table();
+///
+/// assert_eq!(
+///     table.to_string(),
+///     "╔════╤═════════╗
+/// ║ ID │ Title   ║
+/// ╟────┼─────────╢
+/// ║ 1  │ Avenger ║
+/// ╚════╧═════════╝"
+/// );
+/// ```
+pub trait ToTable {
+    /// Returns the header of the columns.
+    fn headers() -> Vec<String>;
+
+    /// Returns the groups displayed above the header, with the number of columns they span.
+    /// No group is displayed by default.
+    fn groups() -> Vec<(String, usize)> {
+        vec![]
+    }
+
+    /// Returns the row displaying the value.
+    fn to_row(&self) -> Row;
+
+    /// Applies the presentation of the type (borders, title, settings of the columns)
+    /// to a table of its values. Nothing is changed by default.
+    fn configure(_table: &mut Table) {}
+}
+
+impl<T: ToTable + ?Sized> ToTable for &T {
+    fn headers() -> Vec<String> {
+        T::headers()
+    }
+
+    fn groups() -> Vec<(String, usize)> {
+        T::groups()
+    }
+
+    fn to_row(&self) -> Row {
+        (**self).to_row()
+    }
+
+    fn configure(table: &mut Table) {
+        T::configure(table)
+    }
+}
+
+/// Builds a table from values implementing [`ToTable`], such as a `Vec`, a slice or an iterator.
+pub trait IntoTable {
+    /// Returns a table with a row for every value, below their header.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use rct::{IntoTable, ToTable};
+    ///
+    /// #[derive(ToTable)]
+    /// struct Movie {
+    ///     id: u32,
+    ///     title: &'static str,
+    /// }
+    ///
+    /// let movies = vec![Movie { id: 1, title: "Avenger" }];
+    ///
+    /// // by reference, the values are kept
+    /// let table = movies.iter().to_table();
+    /// assert_eq!(table.rows().len(), 1);
+    ///
+    /// // or by value
+    /// let table = movies.to_table();
+    /// assert_eq!(table.header().unwrap().cells.len(), 2);
+    /// ```
+    fn to_table(self) -> Table;
+}
+
+impl<I> IntoTable for I
+where
+    I: IntoIterator,
+    I::Item: ToTable,
+{
+    fn to_table(self) -> Table {
+        let mut table = Table::new();
+
+        let groups = <I::Item as ToTable>::groups();
+        if !groups.is_empty() {
+            let groups = groups
+                .into_iter()
+                .map(|(group, span)| (group.cell(), span))
+                .collect();
+            table.add_group_header(groups);
+        }
+        let header = <I::Item as ToTable>::headers()
+            .into_iter()
+            .map(ICell::cell)
+            .collect::<Vec<_>>();
+        table.add_header(header);
+        <I::Item as ToTable>::configure(&mut table);
+
+        for item in self {
+            table.add_row(item.to_row());
+        }
+
+        table
+    }
+}
@@ -0,0 +1,299 @@
+//! Typed values kept by the cells alongside their text.
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The value displayed by a cell, used by the exports and the operations on the table
+/// instead of parsing its text back. It is built with [`Cell::typed`](crate::cell::Cell::typed)
+/// or [`Cell::with_value`](crate::cell::Cell::with_value).
+///
+/// # Examples
+///
+/// ```
+/// use rct::value::Value;
+///
+/// assert_eq!(Value::from(14.87f32), Value::Float(14.87));
+/// assert_eq!(Value::from(None::<u32>), Value::Null);
+/// assert_eq!(Value::from(std::time::UNIX_EPOCH).to_string(), "1970-01-01T00:00:00Z");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    /// Displayed in RFC 3339 format, in UTC.
+    DateTime(SystemTime),
+    /// Displayed as a number of seconds.
+    Duration(Duration),
+}
+
+impl Value {
+    /// Returns the value as a number, if it is one.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Int(value) => Some(*value as f64),
+            Value::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Compares two values with a total order: values of the same kind are compared together,
+    /// integers and floats being compared exactly, and the kinds follow the order of the
+    /// variants, `Null` first.
+    pub(crate) fn total_cmp(&self, other: &Value) -> Ordering {
+        match (self, other) {
+            (Value::Null, Value::Null) => Ordering::Equal,
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::Int(a), Value::Int(b)) => a.cmp(b),
+            (Value::Int(a), Value::Float(b)) => compare_int_float(*a, *b),
+            (Value::Float(a), Value::Int(b)) => compare_int_float(*b, *a).reverse(),
+            (Value::Float(a), Value::Float(b)) => a.total_cmp(b),
+            (Value::DateTime(a), Value::DateTime(b)) => a.cmp(b),
+            (Value::Duration(a), Value::Duration(b)) => a.cmp(b),
+            (a, b) => a.kind().cmp(&b.kind()),
+        }
+    }
+
+    /// Returns the rank of the kind of the value, integers and floats being the same kind.
+    fn kind(&self) -> u8 {
+        match self {
+            Value::Null => 0,
+            Value::Bool(_) => 1,
+            Value::Int(_) | Value::Float(_) => 2,
+            Value::DateTime(_) => 3,
+            Value::Duration(_) => 4,
+        }
+    }
+}
+
+/// Compares an integer with a float exactly, even when the integer has no exact float.
+fn compare_int_float(int: i64, float: f64) -> Ordering {
+    // rounding the integer keeps the order, and an equal float is an integer of the same range
+    match (int as f64).total_cmp(&float) {
+        Ordering::Equal => (int as i128).cmp(&(float as i128)),
+        ordering => ordering,
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => Ok(()),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Int(value) => write!(f, "{}", value),
+            Value::Float(value) => write!(f, "{}", value),
+            Value::DateTime(time) => write_rfc3339(f, *time),
+            Value::Duration(duration) => write!(f, "{}", duration.as_secs_f64()),
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Value {
+                fn from(value: $ty) -> Self {
+                    // the integers too large for an i64 are kept as floats
+                    i64::try_from(value)
+                        .map(Value::Int)
+                        .unwrap_or(Value::Float(value as f64))
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<f32> for Value {
+    fn from(value: f32) -> Self {
+        // going through the text keeps `14.87` instead of `14.869999885559082`
+        Value::Float(value.to_string().parse().unwrap_or(value as f64))
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<SystemTime> for Value {
+    fn from(value: SystemTime) -> Self {
+        Value::DateTime(value)
+    }
+}
+
+impl From<Duration> for Value {
+    fn from(value: Duration) -> Self {
+        Value::Duration(value)
+    }
+}
+
+/// The date-times of `chrono`, the naive ones being read in UTC.
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for Value {
+    fn from(value: chrono::DateTime<Tz>) -> Self {
+        Value::DateTime(value.into())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDateTime> for Value {
+    fn from(value: chrono::NaiveDateTime) -> Self {
+        value.and_utc().into()
+    }
+}
+
+/// The date-times of `time`, the ones without offset being read in UTC.
+#[cfg(feature = "time")]
+impl From<time::OffsetDateTime> for Value {
+    fn from(value: time::OffsetDateTime) -> Self {
+        Value::DateTime(value.into())
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::PrimitiveDateTime> for Value {
+    fn from(value: time::PrimitiveDateTime) -> Self {
+        value.assume_utc().into()
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Value::Null, Into::into)
+    }
+}
+
+/// The date and the time of day of a `SystemTime`, in UTC.
+pub(crate) struct Civil {
+    pub(crate) year: i128,
+    pub(crate) month: i128,
+    pub(crate) day: i128,
+    pub(crate) hour: i128,
+    pub(crate) minute: i128,
+    pub(crate) second: i128,
+    pub(crate) nanos: i128,
+}
+
+impl From<SystemTime> for Civil {
+    fn from(time: SystemTime) -> Self {
+        let nanos = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_nanos() as i128,
+            Err(err) => -(err.duration().as_nanos() as i128),
+        };
+        let seconds = nanos.div_euclid(1_000_000_000);
+        let nanos = nanos.rem_euclid(1_000_000_000);
+        let (days, time) = (seconds.div_euclid(86_400), seconds.rem_euclid(86_400));
+
+        // days since 1970-01-01 to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let month = if month < 10 { month + 3 } else { month - 9 };
+
+        Civil {
+            year: year_of_era + era * 400 + i128::from(month <= 2),
+            month,
+            day,
+            hour: time / 3_600,
+            minute: time % 3_600 / 60,
+            second: time % 60,
+            nanos,
+        }
+    }
+}
+
+/// Writes a time as `1970-01-01T00:00:00Z`, with the fraction of second when there is one.
+fn write_rfc3339(f: &mut Formatter<'_>, time: SystemTime) -> fmt::Result {
+    let civil = Civil::from(time);
+    write!(
+        f,
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        civil.year, civil.month, civil.day, civil.hour, civil.minute, civil.second
+    )?;
+    if civil.nanos > 0 {
+        let fraction = format!("{:09}", civil.nanos);
+        write!(f, ".{}", fraction.trim_end_matches('0'))?;
+    }
+    f.write_str("Z")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::value::Value;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn test_datetime_display() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_007_589_920);
+        assert_eq!(Value::from(time).to_string(), "2001-12-05T22:05:20Z");
+        let time = UNIX_EPOCH + Duration::from_millis(951_782_400_250);
+        assert_eq!(Value::from(time).to_string(), "2000-02-29T00:00:00.25Z");
+    }
+
+    #[test]
+    fn test_datetime_before_epoch() {
+        let time = UNIX_EPOCH - Duration::from_secs(1);
+        assert_eq!(Value::from(time).to_string(), "1969-12-31T23:59:59Z");
+    }
+
+    #[test]
+    fn test_duration() {
+        let value = Value::from(Duration::from_millis(1_500));
+        assert_eq!(value.to_string(), "1.5");
+        assert_eq!(value.as_f64(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_from_chrono() {
+        let time = chrono::DateTime::parse_from_rfc3339("2001-12-05T23:05:20+01:00").unwrap();
+        assert_eq!(Value::from(time).to_string(), "2001-12-05T22:05:20Z");
+        assert_eq!(
+            Value::from(time.naive_local()).to_string(),
+            "2001-12-05T23:05:20Z"
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_from_time() {
+        let time = time::OffsetDateTime::from_unix_timestamp(1_007_589_920).unwrap();
+        assert_eq!(Value::from(time).to_string(), "2001-12-05T22:05:20Z");
+    }
+
+    #[test]
+    fn test_from_integers() {
+        assert_eq!(Value::from(-3i8), Value::Int(-3));
+        assert_eq!(Value::from(u64::MAX), Value::Float(u64::MAX as f64));
+    }
+
+    #[test]
+    fn test_compare_numbers() {
+        use std::cmp::Ordering;
+
+        assert_eq!(Value::Int(2).total_cmp(&Value::Float(10.5)), Ordering::Less);
+        assert_eq!(
+            Value::Int(i64::MAX).total_cmp(&Value::Float(i64::MAX as f64)),
+            Ordering::Less
+        );
+        assert_eq!(
+            Value::Int(2).total_cmp(&Value::Bool(true)),
+            Ordering::Greater
+        );
+    }
+}
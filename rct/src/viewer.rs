@@ -0,0 +1,537 @@
+//! Interactive full-screen viewer of a [`Table`], on the terminal backend of `ratatui`.
+//!
+//! The table is printed with its borders and the styles of its cells, and displayed in a
+//! window scrolled with the keyboard:
+//!
+//! | Keys                          | Action                                            |
+//! |-------------------------------|---------------------------------------------------|
+//! | `↓` `↑`, `j` `k`              | scroll one line down or up                        |
+//! | `PageDown` `PageUp`, `Space`  | scroll one page down or up                        |
+//! | `Home` `End`, `g` `G`         | go to the first or to the last line               |
+//! | `→` `←`, `l` `h`              | scroll right or left                              |
+//! | `/`                           | search, while typing, `Enter` keeping the search  |
+//! | `n` `N`                       | go to the next or to the previous match           |
+//! | `1` to `9`                    | sort by the column, ascending then descending     |
+//! | `0`                           | restore the order of the rows                     |
+//! | `q`, `Esc`                    | quit                                              |
+//!
+//! The lines above the first row, from the top border to the header, stay on screen.
+use crate::sort::Order;
+use crate::table::{Layout, Table};
+use crate::text::display_width;
+use ratatui::backend::Backend;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
+use ratatui::{Frame, Terminal};
+use std::io;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The version of `ratatui` the viewer is built on, to draw it in a terminal or in a test backend.
+pub use ratatui;
+
+/// Number of columns scrolled by `→` and `←`.
+const SCROLL_COLUMNS: usize = 4;
+
+/// State of the interactive viewer of a table: its printed lines, the scroll position,
+/// the search and the sort.
+///
+/// # Examples
+///
+/// ```
+/// use rct::cell::ICell;
+/// use rct::table::Table;
+/// use rct::viewer::ratatui::backend::TestBackend;
+/// use rct::viewer::ratatui::crossterm::event::{KeyCode, KeyEvent};
+/// use rct::viewer::ratatui::Terminal;
+/// use rct::viewer::Viewer;
+/// let mut table = Table::new();
+///
+/// table
+///     .add_header(vec!["ID".cell(), "Title".cell()])
+///     .add_row(vec![1.cell(), "Harry Potter".cell()])
+///     .add_row(vec![2.cell(), "Avenger".cell()]);
+///
+/// let mut viewer = Viewer::new(&table);
+/// // sort by the second column
+/// assert!(viewer.handle_key(KeyEvent::from(KeyCode::Char('2'))));
+///
+/// let mut terminal = Terminal::new(TestBackend::new(24, 8)).unwrap();
+/// terminal.draw(|frame| viewer.draw(frame)).unwrap();
+/// let line = |y| {
+///     (0..24)
+///         .map(|x| terminal.backend().buffer()[(x, y)].symbol())
+///         .collect::<String>()
+/// };
+/// assert_eq!(line(3), "║ 2  │ Avenger      ║   ");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Viewer {
+    /// The table without sort, to restore the order of its rows.
+    original: Table,
+    table: Table,
+    /// Printed lines of the table, split in styled pieces.
+    lines: Vec<Vec<(Style, String)>>,
+    /// Texts of the lines, without styles.
+    texts: Vec<String>,
+    /// Number of lines staying on screen above the rows.
+    frozen: usize,
+    /// Display width of the widest line.
+    width: usize,
+    /// First row line displayed, after the frozen lines.
+    top: usize,
+    /// First column displayed.
+    left: usize,
+    /// Width and number of row lines of the window, at the last draw.
+    window: (usize, usize),
+    sort: Option<(usize, Order)>,
+    query: String,
+    /// Whether the query is being typed.
+    typing: bool,
+    /// Whether no line matches the query.
+    not_found: bool,
+}
+
+impl Viewer {
+    /// Create the viewer of a table, displayed with its borders and its styles. The rows are
+    /// laid out horizontally, whatever the [`Layout`] of the table.
+    pub fn new(table: &Table) -> Viewer {
+        let mut table = table.clone();
+        table.layout = Layout::Horizontal;
+        let mut viewer = Viewer {
+            original: table.clone(),
+            table,
+            lines: vec![],
+            texts: vec![],
+            frozen: 0,
+            width: 0,
+            top: 0,
+            left: 0,
+            window: (80, 23),
+            sort: None,
+            query: String::new(),
+            typing: false,
+            not_found: false,
+        };
+        viewer.print();
+
+        viewer
+    }
+
+    /// Handle a key pressed by the user, see the [module](self) for the keys.
+    /// Returns `false` when the viewer is closed.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return true;
+        }
+        if self.typing {
+            self.type_query(key);
+            return true;
+        }
+
+        let page = self.window.1.max(1);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Down | KeyCode::Char('j') => self.top += 1,
+            KeyCode::Up | KeyCode::Char('k') => self.top = self.top.saturating_sub(1),
+            KeyCode::PageDown | KeyCode::Char(' ') => self.top += page,
+            KeyCode::PageUp | KeyCode::Char('b') => self.top = self.top.saturating_sub(page),
+            KeyCode::Home | KeyCode::Char('g') => self.top = 0,
+            KeyCode::End | KeyCode::Char('G') => self.top = usize::MAX,
+            KeyCode::Right | KeyCode::Char('l') => self.left += SCROLL_COLUMNS,
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.left = self.left.saturating_sub(SCROLL_COLUMNS)
+            }
+            KeyCode::Char('/') => {
+                self.typing = true;
+                self.query.clear();
+                self.not_found = false;
+            }
+            KeyCode::Char('n') => self.find(self.top + 1, true),
+            KeyCode::Char('N') => self.find(self.top, false),
+            KeyCode::Char('0') => {
+                self.sort = None;
+                self.table = self.original.clone();
+                self.print();
+            }
+            KeyCode::Char(digit @ '1'..='9') => {
+                let column = digit as usize - '1' as usize;
+                if column < self.table.columns() {
+                    let order = match self.sort {
+                        Some((sorted, Order::Ascending)) if sorted == column => Order::Descending,
+                        _ => Order::Ascending,
+                    };
+                    self.sort = Some((column, order));
+                    self.table = self.original.clone();
+                    self.table.sort_by_column(column, order);
+                    self.print();
+                }
+            }
+            _ => {}
+        }
+        self.clamp();
+
+        true
+    }
+
+    /// Draw the visible part of the table in the frame, above a status line.
+    pub fn draw(&mut self, frame: &mut Frame) {
+        let area = frame.area();
+        let height = usize::from(area.height.saturating_sub(1));
+        let frozen = self.frozen.min(height);
+        self.window = (usize::from(area.width), height - frozen);
+        self.clamp();
+
+        let rows = self.top + self.frozen..self.top + self.frozen + self.window.1;
+        let lines = (0..frozen)
+            .chain(rows.filter(|index| *index < self.lines.len()))
+            .map(|index| self.visible_line(index))
+            .collect::<Vec<_>>();
+        let table = Rect {
+            height: area.height.saturating_sub(1),
+            ..area
+        };
+        frame.render_widget(Paragraph::new(lines), table);
+
+        let status = Rect {
+            y: area.y + table.height,
+            height: area.height.min(1),
+            ..area
+        };
+        let style = Style::default().add_modifier(Modifier::REVERSED);
+        frame.render_widget(Paragraph::new(self.status()).style(style), status);
+    }
+
+    /// Run the viewer in a terminal until it is closed, drawing it again when the terminal is
+    /// resized (on `SIGWINCH`).
+    ///
+    /// # Errors
+    ///
+    /// Returns the error met while reading the events or drawing in the terminal.
+    pub fn run<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            match event::read()? {
+                Event::Key(key) if !self.handle_key(key) => return Ok(()),
+                // the next draw fits the new size of the terminal
+                Event::Resize(_, _) => terminal.autoresize()?,
+                _ => {}
+            }
+        }
+    }
+
+    /// Print the table in `lines`.
+    fn print(&mut self) {
+        let mut view = String::new();
+        // writing in a `String` does not fail
+        let _ = self.table.fmt_to(&mut view);
+        self.lines = view.lines().map(styled_pieces).collect();
+        self.texts = self
+            .lines
+            .iter()
+            .map(|pieces| pieces.iter().map(|(_, text)| text.as_str()).collect())
+            .collect();
+        self.width = self
+            .texts
+            .iter()
+            .map(|text| display_width(text))
+            .max()
+            .unwrap_or(0);
+        self.frozen = frozen_lines(&self.table).min(self.lines.len());
+        self.clamp();
+    }
+
+    /// Keep the scroll position in the table.
+    fn clamp(&mut self) {
+        let rows = self.lines.len() - self.frozen;
+        self.top = self.top.min(rows.saturating_sub(self.window.1));
+        self.left = self.left.min(self.width.saturating_sub(self.window.0));
+    }
+
+    /// Handle a key pressed while typing the query, searching it at each change.
+    fn type_query(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Enter => self.typing = false,
+            KeyCode::Esc => {
+                self.typing = false;
+                self.query.clear();
+                self.not_found = false;
+            }
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.find(self.top, true);
+            }
+            KeyCode::Char(c) => {
+                self.query.push(c);
+                self.find(self.top, true);
+            }
+            _ => {}
+        }
+    }
+
+    /// Scroll to the first row line matching the query, searching down from the row line
+    /// `from` or up from the line above it.
+    fn find(&mut self, from: usize, forward: bool) {
+        if self.query.is_empty() {
+            self.not_found = false;
+            return;
+        }
+        let rows = self.frozen..self.lines.len();
+        let matches = |index: &usize| match_mask(&self.texts[*index], &self.query).contains(&true);
+        let found = match forward {
+            true => rows.skip(from).find(matches),
+            false => rows.take(from).rev().find(matches),
+        };
+
+        self.not_found = found.is_none();
+        if let Some(index) = found {
+            self.top = index - self.frozen;
+            self.clamp();
+        }
+    }
+
+    /// Returns the visible part of the line at `index`, with the matches of the query reversed.
+    fn visible_line(&self, index: usize) -> Line<'static> {
+        let mask = match_mask(&self.texts[index], &self.query);
+        let (left, right) = (self.left, self.left + self.window.0);
+        let mut spans: Vec<Span<'static>> = vec![];
+        let (mut column, mut position) = (0, 0);
+        // the columns are counted per grapheme cluster, like in the printed table
+        let graphemes = self.lines[index]
+            .iter()
+            .flat_map(|(style, text)| text.graphemes(true).map(move |grapheme| (*style, grapheme)));
+        for (style, grapheme) in graphemes {
+            let chars = grapheme.chars().count();
+            let matched = mask[position..position + chars].contains(&true);
+            position += chars;
+            let (start, end) = (column, column + display_width(grapheme));
+            column = end;
+            let text = if start >= left && end <= right {
+                grapheme.to_string()
+            } else if start < right && end > left {
+                // the visible half of a wide grapheme cut by the window is left blank
+                " ".repeat(end.min(right) - start.max(left))
+            } else {
+                continue;
+            };
+
+            let style = match matched {
+                true => style.add_modifier(Modifier::REVERSED),
+                false => style,
+            };
+            match spans.last_mut() {
+                Some(span) if span.style == style => span.content.to_mut().push_str(&text),
+                _ => spans.push(Span::styled(text, style)),
+            }
+        }
+
+        Line::from(spans)
+    }
+
+    /// Returns the status line: the lines displayed, the sort and the search.
+    fn status(&self) -> String {
+        let rows = self.lines.len() - self.frozen;
+        let first = (self.top + 1).min(rows);
+        let last = (self.top + self.window.1).min(rows);
+        let mut status = format!(" lines {first}-{last} of {rows}");
+        if let Some((column, order)) = self.sort {
+            let name = self
+                .table
+                .header
+                .as_ref()
+                .and_then(|header| header.cells.get(column))
+                .map(|cell| cell.plain_text())
+                .unwrap_or_else(|| format!("column {}", column + 1));
+            let arrow = match order {
+                Order::Ascending => '↑',
+                Order::Descending => '↓',
+            };
+            status.push_str(&format!(" · sorted by {name} {arrow}"));
+        }
+        if self.typing {
+            status.push_str(&format!(" · /{}", self.query));
+        } else if self.not_found {
+            status.push_str(&format!(" · not found: {}", self.query));
+        } else {
+            status.push_str(" · q: quit, /: search, 1-9: sort");
+        }
+
+        status
+    }
+}
+
+impl Table {
+    /// Display the table in an interactive full-screen viewer, until `q` or `Esc` is pressed.
+    /// See the [viewer](crate::viewer) for the keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error met while setting up the terminal, reading the keys or drawing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, no_run
+    /// use rct::cell::ICell;
+    /// use rct::table::Table;
+    /// let mut table = Table::new();
+    ///
+    /// table.add_header(vec!["ID".cell(), "Title".cell()]);
+    /// for id in 0..100 {
+    ///     table.add_row(vec![id.cell(), "Harry Potter".cell()]);
+    /// }
+    ///
+    /// table.view_interactive().unwrap();
+    /// ```
+    pub fn view_interactive(&self) -> io::Result<()> {
+        let mut terminal = ratatui::try_init()?;
+        let result = Viewer::new(self).run(&mut terminal);
+        ratatui::try_restore()?;
+
+        result
+    }
+}
+
+/// Returns the number of lines above the first row of a table: its top border, its title,
+/// its group header and its header.
+fn frozen_lines(table: &Table) -> usize {
+    if table.title.is_none() && table.header.is_none() && table.groups.is_empty() {
+        return 1;
+    }
+
+    // the bottom border of the top takes the place of the separator below the header
+    let mut top = Table {
+        title: table.title.clone(),
+        header: table.header.clone(),
+        groups: table.groups.clone(),
+        column_styles: table.column_styles.clone(),
+        ..table.clone_settings()
+    };
+    for (index, width) in table.set_max_width().into_iter().enumerate() {
+        top.width_column(index, width.saturating_sub(2));
+    }
+    let mut view = String::new();
+    let _ = top.fmt_to(&mut view);
+
+    view.lines().count()
+}
+
+/// Splits a line printed with ANSI styles in pieces of text, each one with its style.
+fn styled_pieces(line: &str) -> Vec<(Style, String)> {
+    let mut pieces = vec![];
+    let mut style = Style::default();
+    let mut rest = line;
+    while let Some(start) = rest.find('\x1b') {
+        if start > 0 {
+            pieces.push((style, rest[..start].to_string()));
+        }
+        rest = &rest[start + 1..];
+        // a style is set with `ESC [ codes m`
+        if let Some((codes, after)) = rest.strip_prefix('[').and_then(|rest| rest.split_once('m')) {
+            style = apply_codes(style, codes);
+            rest = after;
+        }
+    }
+    if !rest.is_empty() {
+        pieces.push((style, rest.to_string()));
+    }
+
+    pieces
+}
+
+/// Returns `style` changed by the codes of an ANSI sequence, like `38;2;255;0;0`.
+fn apply_codes(style: Style, codes: &str) -> Style {
+    let mut codes = codes.split(';').map(|code| code.parse::<u8>().unwrap_or(0));
+    let mut style = style;
+    while let Some(code) = codes.next() {
+        style = match code {
+            0 => Style::default(),
+            1 => style.add_modifier(Modifier::BOLD),
+            2 => style.add_modifier(Modifier::DIM),
+            3 => style.add_modifier(Modifier::ITALIC),
+            4 => style.add_modifier(Modifier::UNDERLINED),
+            5 => style.add_modifier(Modifier::SLOW_BLINK),
+            6 => style.add_modifier(Modifier::RAPID_BLINK),
+            7 => style.add_modifier(Modifier::REVERSED),
+            8 => style.add_modifier(Modifier::HIDDEN),
+            9 => style.add_modifier(Modifier::CROSSED_OUT),
+            38 | 48 => {
+                // only the RGB colors are written by the styles of the cells
+                let rgb = (codes.next(), codes.next(), codes.next(), codes.next());
+                match (code, rgb) {
+                    (38, (Some(2), Some(r), Some(g), Some(b))) => style.fg(Color::Rgb(r, g, b)),
+                    (_, (Some(2), Some(r), Some(g), Some(b))) => style.bg(Color::Rgb(r, g, b)),
+                    _ => style,
+                }
+            }
+            _ => style,
+        };
+    }
+
+    style
+}
+
+/// Returns for every character of `text` whether it is part of a match of `query`,
+/// ignoring the case.
+fn match_mask(text: &str, query: &str) -> Vec<bool> {
+    let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let text = text.chars().map(lower).collect::<Vec<_>>();
+    let query = query.chars().map(lower).collect::<Vec<_>>();
+    let mut mask = vec![false; text.len()];
+    if query.is_empty() {
+        return mask;
+    }
+
+    for start in 0..text.len().saturating_sub(query.len() - 1) {
+        if text[start..start + query.len()] == query[..] {
+            mask[start..start + query.len()].fill(true);
+        }
+    }
+
+    mask
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_styled_pieces() {
+        let line = "║ \u{1b}[38;2;255;0;0m\u{1b}[1mlate\u{1b}[0m\u{1b}[0m │ ok ║";
+        assert_eq!(
+            styled_pieces(line),
+            vec![
+                (Style::default(), "║ ".to_string()),
+                (
+                    Style::default()
+                        .fg(Color::Rgb(255, 0, 0))
+                        .add_modifier(Modifier::BOLD),
+                    "late".to_string()
+                ),
+                (Style::default(), " │ ok ║".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_codes_background() {
+        let style = apply_codes(Style::default(), "48;2;0;0;255");
+        assert_eq!(style, Style::default().bg(Color::Rgb(0, 0, 255)));
+    }
+
+    #[test]
+    fn test_match_mask() {
+        assert_eq!(
+            match_mask("Harry", "ar"),
+            vec![false, true, true, false, false]
+        );
+        assert_eq!(
+            match_mask("Harry", "RY"),
+            vec![false, false, false, true, true]
+        );
+        assert_eq!(match_mask("Harry", ""), vec![false; 5]);
+        assert_eq!(match_mask("H", "Harry"), vec![false]);
+    }
+}
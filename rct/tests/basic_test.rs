@@ -1,6 +1,14 @@
-use rct::cell::ICell;
+use rct::aggregate::Aggregate;
+use rct::cell::{Cell, ICell};
+use rct::format::{NumberFormat, TimeFormat};
+use rct::page::PageSize;
+use rct::sort::{Compare, Order, SortKey};
+use rct::styles::align::Align;
 use rct::styles::borders::BorderStyle;
-use rct::table::Table;
+use rct::styles::color::{split_colors, Colorizer};
+use rct::table::{Layout, Overflow, Table};
+use rct::value::Value;
+use std::time::{Duration, UNIX_EPOCH};
 
 #[test]
 fn basic_table() {
@@ -162,4 +170,706 @@ fn basic_table_empty() {
     let expected = "                                                                                                    \n  ID   Title        is_enabled   price   currency   description                created_at           \n                                                                                                    \n  1    Harry        1            14.87   €          Harry Potter               2001-12-05 22:05:20  \n       Potter                                                                                       \n                                                                                                    \n  2    Spider-man   0            18.80   €          Spider-man, No Way Home.   2018-12-12 09:04:50  \n                                                                                                    \n  3    Avenger      1            18.50   €          Avenger                    2017-10-12 10:34:39  \n                                                                                                    ";
 
     assert_eq!(&table.to_string(), expected)
-}
\ No newline at end of file
+}
+#[test]
+fn table_align_max_width() {
+    let mut table = Table::default();
+
+    table
+        .add_header(vec!["ID".cell(), "Title".cell(), "price".cell()])
+        .add_row(vec![1.cell(), "Harry Potter".cell(), "14.87".cell()])
+        .add_row(vec![2.cell(), "Spider-man, No Way Home.".cell(), "8.8".cell()])
+        .align_column(2, Align::Right)
+        .max_width(12);
+
+    let expected = "
+╔════╤══════════════╤═══════╗
+║ ID │ Title        │ price ║
+╟────┼──────────────┼───────╢
+║ 1  │ Harry Potter │ 14.87 ║
+╟────┼──────────────┼───────╢
+║ 2  │ Spider-man,  │   8.8 ║
+║    │ No Way Home. │       ║
+╚════╧══════════════╧═══════╝";
+
+    assert_eq!("\n".to_string() + &table.to_string(), expected)
+}
+
+struct FailingWriter;
+
+impl std::io::Write for FailingWriter {
+    fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "closed"))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn table_write_to() {
+    let mut table = Table::default();
+
+    table
+        .add_header(vec!["ID".cell(), "Title".cell()])
+        .add_row(vec![1.cell(), "Harry \nPotter".cell()]);
+
+    let mut view = vec![];
+    table.write_to(&mut view).unwrap();
+    assert_eq!(String::from_utf8(view).unwrap(), table.to_string() + "\n");
+
+    let err = table.write_to(&mut FailingWriter).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::BrokenPipe);
+}
+
+#[test]
+fn table_edited_cell() {
+    let mut table = Table::new();
+
+    // a line replaced after the cell was built is measured again
+    let mut cell = "ab".cell();
+    cell.data[0] = "東京".to_string();
+    table
+        .border(BorderStyle::Simple)
+        .add_header(vec!["City".cell()])
+        .add_row(vec![cell])
+        .add_row(vec![Cell::from_lines(vec!["Paris".to_string()])]);
+    let expected = "
++-------+
+| City  |
++-------+
+| 東京  |
++-------+
+| Paris |
++-------+";
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+}
+
+#[test]
+fn table_title_without_rows() {
+    let mut table = Table::new();
+    table.title("Movies".cell());
+
+    let expected = "
+╔════════╗
+║ Movies ║
+╚════════╝";
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+
+    table.add_header(vec!["ID".cell(), "Title".cell()]);
+    let expected = "
+╔════════════╗
+║   Movies   ║
+╟────┬───────╢
+║ ID │ Title ║
+╚════╧═══════╝";
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+}
+
+#[test]
+fn table_title_groups_columns() {
+    let mut table = Table::new();
+
+    table
+        .title("Orders".cell())
+        .add_group_header(vec![("".cell(), 1), ("Customer".cell(), 2)])
+        .add_header(vec!["ID".cell(), "Name".cell(), "Email".cell()])
+        .add_row(vec![1.cell(), "Harry".cell(), "harry@hogwarts.uk".cell()])
+        .max_width_column(2, 10)
+        .overflow_column(2, Overflow::Truncate);
+
+    let expected = "
+╔═════════════════════════╗
+║         Orders          ║
+╟────┬────────────────────╢
+║    │      Customer      ║
+╟────┼───────┬────────────╢
+║ ID │ Name  │ Email      ║
+╟────┼───────┼────────────╢
+║ 1  │ Harry │ harry@hog… ║
+╚════╧═══════╧════════════╝";
+
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+}
+
+#[test]
+fn table_expanded_layout() {
+    let mut table = Table::new();
+
+    table
+        .title("Movies".cell())
+        .layout(Layout::Expanded)
+        .add_header(vec!["ID".cell(), "Title".cell(), "Price".cell()])
+        .add_row(vec![1.cell(), "Harry \nPotter".cell(), "14.87".cell()])
+        .add_row(vec![2.cell(), "Spider-man".cell()])
+        .align_column(2, Align::Right);
+
+    let expected = "
+╔════════════════════╗
+║       Movies       ║
+╟─[ RECORD 1 ]───────╢
+║ ID    │ 1          ║
+║ Title │ Harry      ║
+║       │ Potter     ║
+║ Price │      14.87 ║
+╟─[ RECORD 2 ]───────╢
+║ ID    │ 2          ║
+║ Title │ Spider-man ║
+║ Price │            ║
+╚═══════╧════════════╝";
+
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+}
+
+#[test]
+fn table_auto_layout() {
+    let mut table = Table::new();
+
+    table
+        .border(BorderStyle::Simple)
+        .add_header(vec!["ID".cell(), "Title".cell()])
+        .add_row(vec![1.cell(), "Avenger".cell()]);
+
+    // the horizontal table is 16 columns wide
+    table.layout(Layout::Auto(16));
+    let expected = "
++----+---------+
+| ID │ Title   |
++----+---------+
+| 1  │ Avenger |
++----+---------+";
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+
+    table.layout(Layout::Auto(15));
+    let expected = "
++-[ RECORD 1 ]----+
+| ID    │ 1       |
+| Title │ Avenger |
++-------+---------+";
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+}
+
+#[test]
+fn table_transpose() {
+    let mut table = Table::new();
+
+    table
+        .title("Movies".cell())
+        .add_header(vec!["ID".cell(), "Title".cell(), "Price".cell()])
+        .add_row(vec![1.cell(), "Harry \nPotter".cell(), "14.87".cell()])
+        .add_row(vec![2.cell(), "Spider-man".cell()]);
+
+    let expected = "
+╔═════════════════════════════╗
+║           Movies            ║
+╟───────┬────────┬────────────╢
+║ ID    │ 1      │ 2          ║
+╟───────┼────────┼────────────╢
+║ Title │ Harry  │ Spider-man ║
+║       │ Potter │            ║
+╟───────┼────────┼────────────╢
+║ Price │ 14.87  │            ║
+╚═══════╧════════╧════════════╝";
+
+    let transposed = table.transpose();
+    assert!(transposed.header().is_none());
+    assert_eq!("\n".to_string() + &transposed.to_string(), expected);
+    assert_eq!(
+        transposed.transpose().rows()[1].cells[1].data,
+        vec!["Harry ", "Potter"]
+    );
+}
+
+#[test]
+fn table_sort_mixed_values() {
+    let mut table = Table::new();
+
+    let cells = [
+        || Cell::typed(1.5),
+        || "1.10".cell(),
+        || Cell::typed(1.1),
+        || "1.9".cell(),
+    ];
+    for index in 0..93 {
+        table.add_row(vec![cells[index % cells.len()]()]);
+    }
+    table.sort_by_column(0, Order::Ascending);
+    let texts: Vec<_> = table
+        .rows()
+        .iter()
+        .map(|row| row.cells[0].to_string())
+        .collect();
+
+    // the typed cells come first, then the texts
+    let mut expected = vec!["1.1"; 23];
+    expected.extend(vec!["1.5"; 24]);
+    expected.extend(vec!["1.9"; 23]);
+    expected.extend(vec!["1.10"; 23]);
+    assert_eq!(texts, expected);
+}
+
+#[test]
+fn table_sort() {
+    let mut table = Table::new();
+
+    table
+        .border(BorderStyle::Simple)
+        .add_header(vec!["File".cell(), "Size".cell()])
+        .add_row(vec!["file10".cell(), "2.5".cell()])
+        .add_row(vec!["file9".cell().color("#ff0000"), "12".cell()])
+        .add_row(vec!["file1".cell(), "n/a".cell()])
+        .add_row(vec!["file2".cell(), "12".cell()]);
+
+    table.sort_by(&[
+        SortKey::desc(1).compare(Compare::Numeric),
+        SortKey::asc(0).compare(Compare::with(|a, b| a.len().cmp(&b.len()).then(a.cmp(b)))),
+    ]);
+    let files: Vec<_> = table
+        .rows()
+        .iter()
+        .map(|row| split_colors(&row.cells[0].to_string()))
+        .collect();
+    // the texts that are not numbers are greater than the numbers
+    assert_eq!(files, ["file1", "file2", "file9", "file10"]);
+
+    table.sort_by_column(0, Order::Ascending);
+    let expected = "
++--------+------+
+| File   │ Size |
++--------+------+
+| file1  │ n/a  |
++--------+------+
+| file2  │ 12   |
++--------+------+
+| \u{1b}[38;2;255;0;0mfile9\u{1b}[0m  │ 12   |
++--------+------+
+| file10 │ 2.5  |
++--------+------+";
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+}
+
+#[test]
+fn table_select_filter() {
+    let mut table = Table::new();
+
+    table
+        .border(BorderStyle::Simple)
+        .add_header(vec!["ID".cell(), "Title".cell(), "Price".cell()])
+        .add_row(vec![1.cell(), "Harry Potter".cell(), "14.87".cell()])
+        .add_row(vec![
+            2.cell(),
+            "Spider-man".cell().color("#ff0000"),
+            "8".cell(),
+        ])
+        .add_row(vec![3.cell(), "Avenger".cell()])
+        .align_column(2, Align::Right);
+
+    let title = table.column_index("Title").unwrap();
+    let view = table
+        .filter(|values| values[title].contains('-') || values[2].is_empty())
+        .select(&[2, title]);
+
+    let expected = "
++-------+------------+
+| Price │ Title      |
++-------+------------+
+|     8 │ \u{1b}[38;2;255;0;0mSpider-man\u{1b}[0m |
++-------+------------+
+|       │ Avenger    |
++-------+------------+";
+    assert_eq!("\n".to_string() + &view.to_string(), expected);
+
+    // the table itself is left untouched
+    assert_eq!(table.rows().len(), 3);
+    assert_eq!(table.select(&[]).header().unwrap().cells.len(), 0);
+}
+
+#[test]
+fn table_group_by_footer() {
+    let mut table = Table::new();
+
+    table
+        .add_header(vec!["Genre".cell(), "Price".cell()])
+        .add_row(vec!["Action".cell().color("#ff0000"), "18.80".cell()])
+        .add_row(vec!["Fantasy".cell(), "9.5".cell()])
+        .add_row(vec!["Action".cell(), "n/a".cell()])
+        .align_column(1, Align::Right);
+    let total = table.summary(&[(0, Aggregate::Count), (1, Aggregate::Average)]);
+    table.add_footer(total);
+
+    let report = table.group_by(0, &[(1, Aggregate::Max)]);
+    let expected = "
+╔═════════╤═══════╗
+║ Genre   │ Price ║
+╠═════════╧═══════╣
+║ \u{1b}[38;2;255;0;0mAction\u{1b}[0m          ║
+╟─────────┬───────╢
+║ \u{1b}[38;2;255;0;0mAction\u{1b}[0m  │ 18.80 ║
+╟─────────┼───────╢
+║ Action  │   n/a ║
+╟─────────┼───────╢
+║         │ 18.80 ║
+╠═════════╧═══════╣
+║ Fantasy         ║
+╟─────────┬───────╢
+║ Fantasy │   9.5 ║
+╟─────────┼───────╢
+║         │   9.5 ║
+╠═════════╪═══════╣
+║ 3       │ 14.15 ║
+╚═════════╧═══════╝";
+    assert_eq!("\n".to_string() + &report.to_string(), expected);
+
+    // the footer and the subtotals are left out of the exports
+    assert!(report.rows()[2].is_subtotal());
+    assert_eq!(
+        report.to_csv(),
+        "Genre,Price\nAction,18.80\nAction,n/a\nFantasy,9.5\n"
+    );
+    assert_eq!(
+        report.to_json(),
+        r#"[{"Genre":"Action","Price":"18.80"},{"Genre":"Action","Price":"n/a"},{"Genre":"Fantasy","Price":"9.5"}]"#
+    );
+    assert_eq!(
+        report.select(&[0]).to_html(),
+        "<table>\n<thead>\n<tr><th>Genre</th></tr>\n</thead>\n<tbody>\n\
+         <tr><th colspan=\"1\">Action</th></tr>\n<tr><td>Action</td></tr>\n\
+         <tr><td>Action</td></tr>\n\
+         <tr><th colspan=\"1\">Fantasy</th></tr>\n<tr><td>Fantasy</td></tr>\n\
+         </tbody>\n</table>\n"
+    );
+
+    let mut expanded = table.clone();
+    expanded.layout(Layout::Expanded);
+    assert!(expanded.to_string().contains("╟─[ FOOTER ]─"));
+
+    // sorting mixes the groups, only the footer keeps its section
+    let mut sorted = report.clone();
+    sorted.sort_by_column(1, Order::Descending);
+    assert_eq!(sorted.rows().len(), 3);
+    let view = sorted.to_string();
+    assert_eq!(view.matches('╠').count(), 1);
+    assert!(view.ends_with("║ 3       │ 14.15 ║\n╚═════════╧═══════╝"));
+}
+
+#[test]
+fn table_group_by_summary() {
+    let mut table = Table::new();
+
+    table
+        .add_header(vec!["Genre".cell(), "Price".cell()])
+        .add_row(vec!["Action".cell(), 1.cell()])
+        .add_row(vec!["Fantasy".cell(), 2.cell()])
+        .add_row(vec!["Action".cell(), 3.cell()]);
+    let report = table.group_by(0, &[(1, Aggregate::Sum)]);
+
+    // the subtotals are not counted twice
+    let total = report.summary(&[(1, Aggregate::Sum)]);
+    assert_eq!(total.cells[1].to_string(), "6");
+    assert_eq!(report.filter(|_| true).rows().len(), 3);
+
+    // grouping again makes no group of the subtotals
+    let regrouped = report.group_by(0, &[(1, Aggregate::Sum)]);
+    assert_eq!(regrouped.rows().len(), 5);
+    assert_eq!(
+        regrouped.summary(&[(1, Aggregate::Count)]).cells[1].to_string(),
+        "3"
+    );
+}
+
+#[test]
+fn table_sections_without_header() {
+    let mut table = Table::new();
+
+    table
+        .border(BorderStyle::Simple)
+        .add_section("Week 1".cell())
+        .add_row(vec!["Mon".cell(), 3.cell()])
+        .add_row(vec!["Tue".cell(), 5.cell()]);
+    let expected = "
++---------+
+| Week 1  |
++-----+---+
+| Mon │ 3 |
++-----+---+
+| Tue │ 5 |
++-----+---+";
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+}
+
+#[test]
+fn table_typed_values() {
+    let mut table = Table::new();
+
+    table
+        .add_header(vec!["Title".cell(), "Price".cell(), "Stock".cell()])
+        .add_row(vec![
+            "Harry Potter".cell(),
+            "14.87 €".cell().with_value(14.87),
+            Cell::typed(12),
+        ])
+        .add_row(vec![
+            "Avenger".cell(),
+            "9.50 €".cell().with_value(9.5),
+            Cell::typed(None::<u32>),
+        ])
+        .add_row(vec![
+            "The Hobbit".cell(),
+            "101.00 €".cell().with_value(101),
+            Cell::typed(3),
+        ])
+        .sort_by(&[SortKey::asc(1)]);
+    let total = table.summary(&[(1, Aggregate::Sum), (2, Aggregate::Sum)]);
+    table.add_footer(total);
+
+    let expected = "
+╔══════════════╤══════════╤═══════╗
+║ Title        │ Price    │ Stock ║
+╟──────────────┼──────────┼───────╢
+║ Avenger      │ 9.50 €   │       ║
+╟──────────────┼──────────┼───────╢
+║ Harry Potter │ 14.87 €  │ 12    ║
+╟──────────────┼──────────┼───────╢
+║ The Hobbit   │ 101.00 € │ 3     ║
+╠══════════════╪══════════╪═══════╣
+║              │ 125.37   │ 15    ║
+╚══════════════╧══════════╧═══════╝";
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+    assert_eq!(table.rows()[0].cells[1].value(), Some(&Value::Float(9.5)));
+
+    assert_eq!(
+        table.to_csv(),
+        "Title,Price,Stock\nAvenger,9.5,\nHarry Potter,14.87,12\nThe Hobbit,101,3\n"
+    );
+    assert_eq!(
+        table.to_json(),
+        "[{\"Title\":\"Avenger\",\"Price\":9.5,\"Stock\":null},\
+         {\"Title\":\"Harry Potter\",\"Price\":14.87,\"Stock\":12},\
+         {\"Title\":\"The Hobbit\",\"Price\":101,\"Stock\":3}]"
+    );
+}
+
+#[test]
+fn table_format_column() {
+    let mut table = Table::new();
+
+    table
+        .border(BorderStyle::Simple)
+        .format_column(1, NumberFormat::currency("$"))
+        .format_column(2, NumberFormat::si("W").separators('.', ','))
+        .align_column(1, Align::Right)
+        .add_header(vec!["Name".cell(), "Price".cell(), "Power".cell()])
+        .add_row(vec![
+            "Heater".cell(),
+            Cell::typed(1234.5).color("#ff0000"),
+            Cell::typed(2500),
+        ])
+        .add_row(vec!["Bulb".cell(), Cell::typed(-3), "n/a".cell()])
+        // the digits of the escape sequence are not the value
+        .add_row(vec!["Fan".cell(), Cell::typed(2).color("#ff0000"), "".cell()]);
+    let expected = "
++--------+-----------+--------+
+| Name   │     Price │ Power  |
++--------+-----------+--------+
+| Heater │ \u{1b}[38;2;255;0;0m$1,234.50\u{1b}[0m │ 2,5 kW |
++--------+-----------+--------+
+| Bulb   │    -$3.00 │ n/a    |
++--------+-----------+--------+
+| Fan    │     \u{1b}[38;2;255;0;0m$2.00\u{1b}[0m │        |
++--------+-----------+--------+";
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+
+    assert_eq!(
+        table.to_markdown(),
+        "| Name | Price | Power |\n| --- | ---: | --- |\n\
+         | Heater | $1,234.50 | 2,5 kW |\n| Bulb | -$3.00 | n/a |\n| Fan | $2.00 |  |\n"
+    );
+    assert_eq!(
+        table.to_csv(),
+        "Name,Price,Power\nHeater,1234.5,2500\nBulb,-3,n/a\nFan,2,\n"
+    );
+}
+
+#[test]
+fn table_durations_and_times() {
+    let start = UNIX_EPOCH + Duration::from_secs(1_007_589_920);
+    let mut table = Table::new();
+
+    table
+        .border(BorderStyle::Simple)
+        .format_column(2, TimeFormat::relative(start + Duration::from_secs(7_200)))
+        .add_header(vec!["Job".cell(), "Took".cell(), "Started".cell()])
+        .add_row(vec![
+            "build".cell(),
+            Cell::duration(Duration::from_secs(7_985)),
+            Cell::time(start),
+        ])
+        .add_row(vec![
+            "test".cell(),
+            Cell::duration(Duration::from_secs(45 * 60)),
+            Cell::time(start + Duration::from_secs(5_400)),
+        ])
+        .sort_by(&[SortKey::asc(1)]);
+    let expected = "
++-------+--------+----------------+
+| Job   │ Took   │ Started        |
++-------+--------+----------------+
+| test  │ 45m    │ 30 minutes ago |
++-------+--------+----------------+
+| build │ 2h 13m │ 2 hours ago    |
++-------+--------+----------------+";
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+
+    assert_eq!(
+        table.to_csv(),
+        "Job,Took,Started\ntest,2700,2001-12-05T23:35:20Z\nbuild,7985,2001-12-05T22:05:20Z\n"
+    );
+}
+
+#[test]
+fn table_pages() {
+    let mut table = Table::new();
+
+    table
+        .border(BorderStyle::Simple)
+        .add_header(vec!["ID".cell(), "Title".cell()])
+        .add_section("Fantasy".cell())
+        .add_row(vec![1.cell(), "Harry\nPotter".cell()])
+        .add_row(vec![2.cell(), "The Hobbit".cell()])
+        .add_section("Action".cell())
+        .add_row(vec![3.cell(), "Avenger".cell()])
+        .add_footer(vec!["".cell(), "3 movies".cell()]);
+
+    let pages = table.pages(PageSize::Lines(10));
+    let views: Vec<_> = pages.iter().map(|page| page.to_string()).collect();
+    let expected = [
+        "
++----+------------+
+| ID │ Title      |
++====+============+
+| Fantasy         |
++----+------------+
+| 1  │ Harry      |
+|    │ Potter     |
++----+------------+
+| 2  │ The Hobbit |
++----+------------+",
+        "
++----+------------+
+| ID │ Title      |
++====+============+
+| Action          |
++----+------------+
+| 3  │ Avenger    |
++====+============+
+|    │ 3 movies   |
++----+------------+",
+    ];
+    assert_eq!(views.len(), 2);
+    for (view, expected) in views.iter().zip(expected) {
+        assert_eq!("\n".to_string() + view, expected);
+        assert!(view.lines().count() <= 10);
+    }
+
+    assert_eq!(table.pages(PageSize::Rows(1)).len(), 3);
+    assert_eq!(Table::new().pages(PageSize::Rows(10)).len(), 1);
+
+    // without header, with a title and wrapped cells, every page is as full as it can be
+    let mut table = Table::new();
+    table
+        .title("Movies".cell())
+        .max_width_column(1, 6)
+        .add_section("Fantasy".cell())
+        .add_row(vec![1.cell(), "Harry Potter".cell()])
+        .add_row(vec![2.cell(), "Avenger".cell()])
+        .add_row(vec![3.cell(), "Spider-man, No Way Home".cell()])
+        .add_row(vec![4.cell(), "Dune".cell()])
+        .add_footer(vec!["".cell(), "4 movies".cell()]);
+    for lines in 6..20 {
+        let pages = table.pages(PageSize::Lines(lines));
+        for (index, page) in pages.iter().enumerate() {
+            let height = page.to_string().lines().count();
+            assert!(page.rows().len() == 1 || height <= lines, "{lines}: {page}");
+            if let Some(row) = pages.get(index + 1).and_then(|next| next.rows().first()) {
+                let mut fuller = page.clone();
+                fuller.add_row(row.clone());
+                assert!(fuller.to_string().lines().count() > lines, "{lines}: {page}");
+            }
+        }
+    }
+}
+
+#[test]
+fn table_split_columns() {
+    let mut table = Table::new();
+
+    table
+        .title("Movies".cell())
+        .layout(Layout::Split(40))
+        .freeze_column(0)
+        .chunk_label(true)
+        .add_header(vec![
+            "ID".cell(),
+            "Title".cell(),
+            "Director".cell(),
+            "Year".cell(),
+            "Price".cell(),
+        ])
+        .add_row(vec![
+            1.cell(),
+            "Harry Potter".cell(),
+            "Chris Columbus".cell(),
+            2001.cell(),
+            "14.87".cell(),
+        ])
+        .add_row(vec![
+            2.cell(),
+            "Spider-man".cell(),
+            "Sam Raimi".cell(),
+            2002.cell(),
+            "18.80".cell(),
+        ])
+        .add_footer(vec![
+            "".cell(),
+            "2 movies".cell(),
+            "".cell(),
+            "".cell(),
+            "33.67".cell(),
+        ]);
+
+    let expected = r#"
+╔════════════════════════════════════╗
+║               Movies               ║
+║          columns 2–3 of 5          ║
+╟────┬──────────────┬────────────────╢
+║ ID │ Title        │ Director       ║
+╟────┼──────────────┼────────────────╢
+║ 1  │ Harry Potter │ Chris Columbus ║
+╟────┼──────────────┼────────────────╢
+║ 2  │ Spider-man   │ Sam Raimi      ║
+╠════╪══════════════╪════════════════╣
+║    │ 2 movies     │                ║
+╚════╧══════════════╧════════════════╝
+
+╔═══════════════════╗
+║      Movies       ║
+║ columns 4–5 of 5  ║
+╟────┬──────┬───────╢
+║ ID │ Year │ Price ║
+╟────┼──────┼───────╢
+║ 1  │ 2001 │ 14.87 ║
+╟────┼──────┼───────╢
+║ 2  │ 2002 │ 18.80 ║
+╠════╪══════╪═══════╣
+║    │      │ 33.67 ║
+╚════╧══════╧═══════╝"#;
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+    assert_eq!(table.split_columns(40).len(), 2);
+
+    // a table narrow enough is displayed whole, its frozen columns in place
+    table.layout(Layout::Split(80));
+    assert!(table
+        .to_string()
+        .contains("║ ID │ Title        │ Director       │ Year │ Price ║"));
+}
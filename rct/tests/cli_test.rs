@@ -0,0 +1,127 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn rct(args: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_rct"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn cli_csv() {
+    let input = "id,title,price\n1,Harry Potter,14.87\n2,Spider-man,18.80\n";
+
+    let expected = "
++----+--------------+-------+
+| id │ title        │ price |
++----+--------------+-------+
+| 1  │ Harry Potter │ 14.87 |
++----+--------------+-------+
+| 2  │ Spider-man   │ 18.80 |
++----+--------------+-------+
+";
+
+    assert_eq!(
+        "\n".to_string() + &rct(&["--border", "simple"], input),
+        expected
+    )
+}
+
+#[test]
+fn cli_ndjson_columns() {
+    let input =
+        "{\"id\": 1, \"title\": \"Avenger\", \"price\": 18.5}\n{\"id\": 2, \"price\": 14}\n";
+
+    let expected = "| price | id |\n| ---: | --- |\n| 18.5 | 1 |\n| 14 | 2 |\n";
+
+    assert_eq!(
+        rct(
+            &["-o", "markdown", "-c", "price,1", "-a", "price=right"],
+            input
+        ),
+        expected
+    )
+}
+
+#[test]
+fn cli_json_object() {
+    let input = "{\n  \"id\": 1,\n  \"title\": \"Avenger\"\n}\n";
+
+    assert_eq!(
+        rct(&["-o", "markdown"], input),
+        "| id | title |\n| --- | --- |\n| 1 | Avenger |\n"
+    );
+    assert_eq!(
+        rct(&["-o", "markdown"], "{\"id\": 1}\n{\"id\": 2}\n"),
+        "| id |\n| --- |\n| 1 |\n| 2 |\n"
+    )
+}
+
+#[test]
+fn cli_expanded() {
+    let input = "id,title\n1,Avenger\n";
+
+    let expected = "
++-[ RECORD 1 ]----+
+| id    │ 1       |
+| title │ Avenger |
++-------+---------+
+";
+
+    assert_eq!(
+        "\n".to_string() + &rct(&["-b", "simple", "--layout", "expanded"], input),
+        expected
+    )
+}
+
+#[test]
+fn cli_files_with_different_headers() {
+    let dir = std::env::temp_dir().join(format!("rct_cli_test_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let first = dir.join("first.csv");
+    let second = dir.join("second.csv");
+    std::fs::write(&first, "id,title\n1,Avenger\n").unwrap();
+    std::fs::write(&second, "title,price,id\nDune,9.5,2\n").unwrap();
+
+    let expected = "id,title,price\n1,Avenger,\n2,Dune,9.5\n";
+
+    let output = rct(
+        &[
+            "-o",
+            "csv",
+            first.to_str().unwrap(),
+            second.to_str().unwrap(),
+        ],
+        "",
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(output, expected)
+}
+
+#[test]
+fn cli_invalid_color() {
+    let output = Command::new(env!("CARGO_BIN_EXE_rct"))
+        .args(["--color", "#zzzzzz"])
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("invalid color `#zzzzzz`"), "{stderr}");
+}
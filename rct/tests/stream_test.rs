@@ -0,0 +1,82 @@
+use rct::cell::ICell;
+use rct::stream::{StreamTable, Widths};
+use rct::styles::borders::BorderStyle;
+use rct::table::Overflow;
+use std::io;
+
+#[test]
+fn stream_estimate() {
+    let mut view = vec![];
+    {
+        let mut stream = StreamTable::new(&mut view, Widths::Estimate(2));
+        stream
+            .border(BorderStyle::Simple)
+            .overflow(Overflow::Truncate);
+        stream
+            .add_header(vec!["ID".cell(), "Title".cell()])
+            .unwrap();
+        stream.push(vec![1.cell(), "Avenger".cell()]).unwrap();
+        stream.push(vec![2.cell(), "Spider-man".cell()]).unwrap();
+        stream.push(vec![3.cell(), "Harry Potter".cell()]).unwrap();
+        // the bottom border is written on drop
+    }
+
+    let expected = "
++----+------------+
+| ID │ Title      |
++----+------------+
+| 1  │ Avenger    |
++----+------------+
+| 2  │ Spider-man |
++----+------------+
+| 3  │ Harry Pot… |
++----+------------+
+";
+
+    assert_eq!(
+        "\n".to_string() + &String::from_utf8(view).unwrap(),
+        expected
+    )
+}
+
+#[test]
+fn stream_fixed_writes_rows_when_pushed() {
+    let mut stream = StreamTable::new(vec![], Widths::Fixed(vec![2, 5]));
+    stream
+        .add_header(vec!["ID".cell(), "Title".cell()])
+        .unwrap();
+    stream.push(vec![1.cell(), "Avenger".cell()]).unwrap();
+
+    let view = stream.finish().unwrap();
+
+    let expected = "
+╔════╤═══════╗
+║ ID │ Title ║
+╟────┼───────╢
+║ 1  │ Aveng ║
+║    │ er    ║
+╚════╧═══════╝
+";
+
+    assert_eq!(
+        "\n".to_string() + &String::from_utf8(view).unwrap(),
+        expected
+    )
+}
+
+#[test]
+fn stream_header_after_rows() {
+    let mut stream = StreamTable::new(vec![], Widths::Fixed(vec![2]));
+    stream.push(vec![1.cell()]).unwrap();
+    let result = stream.add_header(vec!["ID".cell()]);
+    assert!(matches!(result, Err(error) if error.kind() == io::ErrorKind::InvalidInput));
+
+    let mut stream = StreamTable::new(vec![], Widths::Estimate(2));
+    stream.add_header(vec!["ID".cell()]).unwrap();
+    assert!(stream.add_header(vec!["ID".cell()]).is_err());
+    stream.push(vec![1.cell()]).unwrap();
+
+    let view = String::from_utf8(stream.finish().unwrap()).unwrap();
+    assert_eq!(view.matches('╔').count(), 1);
+    assert_eq!(view.matches("ID").count(), 1);
+}
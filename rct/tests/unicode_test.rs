@@ -0,0 +1,122 @@
+use rct::cell::ICell;
+use rct::styles::color::Colorizer;
+use rct::table::Table;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// Width of a rendered line as displayed by a terminal.
+fn line_width(line: &str) -> usize {
+    rct::styles::color::split_colors(line)
+        .graphemes(true)
+        .map(|grapheme| grapheme.width())
+        .sum()
+}
+
+/// Every line of a table must have the same width for the borders to be aligned.
+fn assert_aligned(table: &Table) {
+    let view = table.to_string();
+    let widths: Vec<usize> = view.lines().map(line_width).collect();
+    assert!(
+        widths.windows(2).all(|pair| pair[0] == pair[1]),
+        "misaligned table:\n{view}"
+    );
+}
+
+#[test]
+fn cjk_table() {
+    let mut table = Table::new();
+
+    table
+        .add_header(vec!["ID".cell(), "Title".cell(), "Country".cell()])
+        .add_row(vec![1.cell(), "千と千尋の神隠し".cell(), "日本".cell()])
+        .add_row(vec![2.cell(), "기생충".cell(), "한국".cell()])
+        .add_row(vec![3.cell(), "ﾊﾝｶｸ".cell(), "中国".cell()]);
+
+    let expected = "
+╔════╤══════════════════╤═════════╗
+║ ID │ Title            │ Country ║
+╟────┼──────────────────┼─────────╢
+║ 1  │ 千と千尋の神隠し │ 日本    ║
+╟────┼──────────────────┼─────────╢
+║ 2  │ 기생충           │ 한국    ║
+╟────┼──────────────────┼─────────╢
+║ 3  │ ﾊﾝｶｸ             │ 中国    ║
+╚════╧══════════════════╧═════════╝";
+
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+    assert_aligned(&table);
+}
+
+#[test]
+fn emoji_table() {
+    let mut table = Table::new();
+
+    table
+        .add_header(vec!["Emoji".cell(), "Name".cell()])
+        .add_row(vec!["👨‍👩‍👧".cell(), "family".cell()])
+        .add_row(vec!["🇫🇷".cell(), "flag".cell()])
+        .add_row(vec!["👍🏽".cell(), "thumbs up".cell()])
+        .add_row(vec!["❤️".cell().color("#ff0000"), "heart".cell()]);
+
+    let expected = "
+╔═══════╤═══════════╗
+║ Emoji │ Name      ║
+╟───────┼───────────╢
+║ 👨‍👩‍👧    │ family    ║
+╟───────┼───────────╢
+║ 🇫🇷    │ flag      ║
+╟───────┼───────────╢
+║ 👍🏽    │ thumbs up ║
+╟───────┼───────────╢
+║ \u{1b}[38;2;255;0;0m❤️\u{1b}[0m    │ heart     ║
+╚═══════╧═══════════╝";
+
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+    assert_aligned(&table);
+}
+
+#[test]
+fn combining_marks_table() {
+    let mut table = Table::new();
+
+    table
+        .add_header(vec!["Word".cell(), "Language".cell()])
+        .add_row(vec!["cafe\u{301}".cell(), "French".cell()])
+        .add_row(vec!["n\u{303}andu\u{301}".cell(), "Spanish".cell()])
+        .add_row(vec![
+            "Z\u{351}\u{36b}\u{343}a\u{36a}\u{302}lgo".cell(),
+            "Zalgo".cell(),
+        ]);
+
+    assert_aligned(&table);
+}
+
+#[test]
+fn wrap_and_truncate_wide_characters() {
+    let mut table = Table::new();
+
+    table
+        .add_header(vec!["Title".cell()])
+        .add_row(vec!["千と千尋の神隠し".cell()])
+        .add_row(vec!["👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧".cell()])
+        .max_width(5);
+
+    let expected = "
+╔═══════╗
+║ Title ║
+╟───────╢
+║ 千と  ║
+║ 千尋  ║
+║ の神  ║
+║ 隠し  ║
+╟───────╢
+║ 👨‍👩‍👧👨‍👩‍👧  ║
+║ 👨‍👩‍👧    ║
+╚═══════╝";
+
+    assert_eq!("\n".to_string() + &table.to_string(), expected);
+    assert_aligned(&table);
+
+    let cell = "千と千尋の神隠し".cell().truncate(5);
+    assert_eq!(cell.data, vec!["千と…"]);
+}
@@ -0,0 +1,227 @@
+#![cfg(feature = "tui")]
+
+use rct::cell::ICell;
+use rct::table::Table;
+use rct::viewer::ratatui::backend::TestBackend;
+use rct::viewer::ratatui::crossterm::event::{KeyCode, KeyEvent};
+use rct::viewer::ratatui::text::Span;
+use rct::viewer::ratatui::Terminal;
+use rct::viewer::Viewer;
+
+fn movies() -> Table {
+    let mut table = Table::new();
+
+    table.add_header(vec!["ID".cell(), "Title".cell(), "Price".cell()]);
+    let titles = [
+        "Harry Potter",
+        "Spider-man",
+        "Avenger",
+        "Dune",
+        "Matrix",
+        "Alien",
+    ];
+    for (id, title) in titles.iter().enumerate() {
+        table.add_row(vec![(id + 1).cell(), title.cell(), "14.87".cell()]);
+    }
+
+    table
+}
+
+fn press(viewer: &mut Viewer, keys: &str) {
+    for c in keys.chars() {
+        assert!(viewer.handle_key(KeyEvent::from(KeyCode::Char(c))));
+    }
+}
+
+fn screen(terminal: &mut Terminal<TestBackend>, viewer: &mut Viewer) -> String {
+    let frame = terminal.draw(|frame| viewer.draw(frame)).unwrap();
+    let buffer = frame.buffer;
+    let lines = (0..buffer.area.height)
+        .map(|y| {
+            let mut line = String::new();
+            let mut x = 0;
+            while x < buffer.area.width {
+                // a wide character covers the cell after it
+                let symbol = buffer[(x, y)].symbol();
+                line.push_str(symbol);
+                x += Span::raw(symbol).width().max(1) as u16;
+            }
+            line.trim_end().to_string()
+        })
+        .collect::<Vec<_>>();
+
+    "\n".to_string() + &lines.join("\n")
+}
+
+#[test]
+fn viewer_scroll() {
+    let mut terminal = Terminal::new(TestBackend::new(20, 8)).unwrap();
+    let mut viewer = Viewer::new(&movies());
+
+    let expected = "
+╔════╤══════════════
+║ ID │ Title
+╟────┼──────────────
+║ 1  │ Harry Potter
+╟────┼──────────────
+║ 2  │ Spider-man
+╟────┼──────────────
+ lines 1-4 of 12 · q";
+    assert_eq!(screen(&mut terminal, &mut viewer), expected);
+
+    // the header stays on screen
+    press(&mut viewer, "jjjll");
+    let expected = "
+════════════╤═══════
+itle        │ Price
+────────────┼───────
+────────────┼───────
+venger      │ 14.87
+────────────┼───────
+une         │ 14.87
+ lines 4-7 of 12 · q";
+    assert_eq!(screen(&mut terminal, &mut viewer), expected);
+
+    // the last line is at the bottom
+    press(&mut viewer, "Gh");
+    let expected = "
+═╤══════════════╤═══
+ │ Title        │ Pr
+─┼──────────────┼───
+ │ Matrix       │ 14
+─┼──────────────┼───
+ │ Alien        │ 14
+═╧══════════════╧═══
+ lines 9-12 of 12 ·";
+    assert_eq!(screen(&mut terminal, &mut viewer), expected);
+}
+
+#[test]
+fn viewer_search_and_sort() {
+    let mut terminal = Terminal::new(TestBackend::new(34, 6)).unwrap();
+    let mut viewer = Viewer::new(&movies());
+    screen(&mut terminal, &mut viewer);
+
+    press(&mut viewer, "/dUn");
+    let expected = "
+╔════╤══════════════╤═══════╗
+║ ID │ Title        │ Price ║
+╟────┼──────────────┼───────╢
+║ 4  │ Dune         │ 14.87 ║
+╟────┼──────────────┼───────╢
+ lines 7-8 of 12 · /dUn";
+    assert_eq!(screen(&mut terminal, &mut viewer), expected);
+
+    assert!(viewer.handle_key(KeyEvent::from(KeyCode::Enter)));
+    press(&mut viewer, "n");
+    assert!(screen(&mut terminal, &mut viewer).ends_with(" · not found: dUn"));
+
+    // descending sort on the second key press
+    press(&mut viewer, "22g");
+    let expected = "
+╔════╤══════════════╤═══════╗
+║ ID │ Title        │ Price ║
+╟────┼──────────────┼───────╢
+║ 2  │ Spider-man   │ 14.87 ║
+╟────┼──────────────┼───────╢
+ lines 1-2 of 12 · sorted by Title";
+    assert_eq!(screen(&mut terminal, &mut viewer), expected);
+
+    assert!(!viewer.handle_key(KeyEvent::from(KeyCode::Char('q'))));
+}
+
+#[test]
+fn viewer_resize() {
+    let mut terminal = Terminal::new(TestBackend::new(34, 6)).unwrap();
+    let mut viewer = Viewer::new(&movies());
+    press(&mut viewer, "G");
+    screen(&mut terminal, &mut viewer);
+
+    terminal.backend_mut().resize(34, 20);
+    terminal.autoresize().unwrap();
+    let screen = screen(&mut terminal, &mut viewer);
+    assert!(screen.contains("║ 1  │ Harry Potter │ 14.87 ║"));
+    assert!(screen.contains("╚════╧══════════════╧═══════╝"));
+    assert!(screen.ends_with(" lines 1-12 of 12 · q: quit, /: se"));
+}
+
+#[test]
+fn viewer_wide_chars() {
+    let mut terminal = Terminal::new(TestBackend::new(10, 5)).unwrap();
+    let mut table = Table::new();
+    table
+        .add_header(vec!["ID".cell(), "City".cell(), "Ward".cell()])
+        .add_row(vec![1.cell(), "東京都".cell(), "Chiyoda".cell()]);
+    let mut viewer = Viewer::new(&table);
+
+    // a wide character cut by an edge of the window keeps the next ones in their columns
+    let expected = "
+╔════╤════
+║ ID │ Cit
+╟────┼────
+║ 1  │ 東
+ lines 1-1";
+    assert_eq!(screen(&mut terminal, &mut viewer), expected);
+
+    press(&mut viewer, "ll");
+    let expected = "
+══════╤═══
+ity   │ Wa
+──────┼───
+ 京都 │ Ch
+ lines 1-1";
+    assert_eq!(screen(&mut terminal, &mut viewer), expected);
+}
+
+#[test]
+fn viewer_grapheme_clusters() {
+    let mut terminal = Terminal::new(TestBackend::new(15, 5)).unwrap();
+    let mut table = Table::new();
+    table
+        .add_header(vec!["Job".cell(), "ID".cell()])
+        .add_row(vec!["👩‍🔬 lab".cell(), 1.cell()]);
+    let mut viewer = Viewer::new(&table);
+
+    // the table fits, the cluster taking two columns
+    let expected = "
+╔════════╤════╗
+║ Job    │ ID ║
+╟────────┼────╢
+║ 👩‍🔬 lab │ 1  ║
+ lines 1-1 of 2";
+    press(&mut viewer, "l");
+    assert_eq!(screen(&mut terminal, &mut viewer), expected);
+
+    terminal.backend_mut().resize(12, 5);
+    terminal.autoresize().unwrap();
+    screen(&mut terminal, &mut viewer);
+    press(&mut viewer, "l");
+    let expected = "
+══════╤════╗
+ob    │ ID ║
+──────┼────╢
+  lab │ 1  ║
+ lines 1-1 o";
+    assert_eq!(screen(&mut terminal, &mut viewer), expected);
+}
+
+#[test]
+fn viewer_search_backward() {
+    let mut terminal = Terminal::new(TestBackend::new(34, 6)).unwrap();
+    let mut viewer = Viewer::new(&movies());
+    screen(&mut terminal, &mut viewer);
+
+    press(&mut viewer, "/arry");
+    assert!(viewer.handle_key(KeyEvent::from(KeyCode::Enter)));
+    assert!(screen(&mut terminal, &mut viewer).contains(" · q: quit"));
+
+    // the line at the top is not searched again
+    press(&mut viewer, "N");
+    assert!(screen(&mut terminal, &mut viewer).ends_with(" · not found: arry"));
+
+    // from the bottom, the search down finds nothing and the search up the first row
+    press(&mut viewer, "G/Potter");
+    assert!(viewer.handle_key(KeyEvent::from(KeyCode::Enter)));
+    press(&mut viewer, "N");
+    assert!(screen(&mut terminal, &mut viewer).contains("║ 1  │ Harry Potter │ 14.87 ║"));
+}
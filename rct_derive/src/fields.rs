@@ -1,78 +1,261 @@
 use proc_macro2::{Span, TokenStream};
-use quote::ToTokens;
+use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    Attribute, Data as SynData, DeriveInput, Expr, ExprLit, Field as SynField, Fields, Ident,
-    Index, Lit, LitStr, Meta, Result, Token,
+    Attribute, Data as SynData, DeriveInput, Expr, ExprLit, ExprPath, Field as SynField, Fields,
+    Ident, Index, Lit, LitStr, Meta, MetaNameValue, Result, Token, Type, Variant as SynVariant,
 };
 
+use crate::utils::RenameRule;
+
 pub struct Data<'a> {
     pub struct_name: &'a Ident,
-    fields: Vec<&'a SynField>,
+    pub container: Container,
+    /// `true` when the rows are the variants of an enum.
+    pub is_enum: bool,
+    /// The struct itself, or every variant of the enum.
+    variants: Vec<(Option<&'a SynVariant>, &'a Fields)>,
+}
+
+/// A struct or a variant of an enum, displayed as a row.
+pub struct Variant {
+    /// Path of the struct or of the variant, used in patterns.
+    pub path: TokenStream,
+    /// Value of the discriminant column, `rename` or the name of the variant.
+    pub name: Option<LitStr>,
+    pub fields: Vec<Field>,
+}
+
+/// Attributes set on the struct or enum itself.
+#[derive(Default)]
+pub struct Container {
+    pub rename_all: Option<RenameRule>,
+    /// Header of the discriminant column of enums, `#[table(tag = "Kind")]`.
+    pub tag: Option<LitStr>,
+    /// Style of the borders, `#[table(border = "simple")]`.
+    pub border: Option<TokenStream>,
+    /// Title displayed above the header, `#[table(title = "Movies")]`.
+    pub title: Option<LitStr>,
+    /// Layout of the rows, `#[table(layout = "expanded")]`.
+    pub layout: Option<TokenStream>,
+    /// Function turning a reference to the value in the `Style` of its row.
+    pub row_style_with: Option<ExprPath>,
+    pub placeholders: Placeholders,
+}
+
+/// How `Option`, `bool` and collections are displayed, set on the struct or on a field.
+#[derive(Default, Clone)]
+pub struct Placeholders {
+    /// Text displayed for `None`, `#[table(none = "-")]`.
+    pub none: Option<LitStr>,
+    /// Texts displayed for `true` and `false`, `#[table(bool = "yes/no")]`.
+    pub bool: Option<(LitStr, LitStr)>,
+    /// Separator of the items of a collection, `#[table(join = "\n")]`.
+    pub join: Option<LitStr>,
 }
 
 pub struct Field {
     pub ident: TokenStream,
-    pub name: Option<LitStr>,
+    /// Header of the column, `rename` or the name of the field.
+    pub name: LitStr,
+    pub rename: Option<LitStr>,
     pub font: Option<Expr>,
     pub color: Option<LitStr>,
     pub bg: Option<LitStr>,
+    /// Function turning a reference to the field in a value implementing `Display`.
+    pub display_with: Option<ExprPath>,
+    /// Format string used instead of `Display`, e.g. `"{:.2}"`.
+    pub format: Option<LitStr>,
+    /// Function turning a reference to the field in the `Style` of its cell.
+    pub style_with: Option<ExprPath>,
+    pub placeholders: Placeholders,
+    pub ty: Type,
+    pub skip: bool,
+    /// The columns of the field, whose type derives `ToTable`, are displayed inline.
+    pub flatten: bool,
+    /// Prefix of the headers of a flattened field, `#[table(flatten, prefix = "customer ")]`.
+    pub prefix: Option<LitStr>,
+    /// Header spanning the columns of a flattened field, `#[table(flatten, group = "Customer")]`.
+    pub group: Option<LitStr>,
+    /// The field, whose type derives `ToTable`, is displayed as a table inside the cell.
+    pub nested: bool,
+    /// Position of the column, `#[table(order = 1)]`.
+    pub order: Option<usize>,
+    /// Alignment of the column, `#[table(align = "right")]`.
+    pub align: Option<TokenStream>,
+    /// Width of the contents of the column, `#[table(width = 20)]`.
+    pub width: Option<usize>,
+    /// Max width of the contents of the column, `#[table(max_width = 20)]`.
+    pub max_width: Option<usize>,
+    /// How wider contents are displayed, `#[table(wrap)]` or `#[table(truncate)]`.
+    pub overflow: Option<TokenStream>,
+    /// Format of the typed numbers of the column, e.g. `#[table(currency = "$", decimals = 0)]`.
+    pub number: Option<TokenStream>,
+    /// The column is repeated in every chunk of a split table, `#[table(freeze)]`.
+    pub freeze: bool,
     pub span: Option<Span>,
 }
 
 impl<'a> Data<'a> {
     pub fn new(input: &'a DeriveInput) -> syn::Result<Self> {
         let struct_name = &input.ident;
-        let fields = match &input.data {
+        let container = Container::new(&input.attrs)?;
+        let variants = match &input.data {
             SynData::Struct(s) => match &s.fields {
-                Fields::Named(field_named) => &field_named.named,
-                Fields::Unnamed(_) => {
-                    return Err(syn::Error::new_spanned(
-                        input,
-                        "rct derive macros can only supported structs named",
-                    ))
-                }
                 Fields::Unit => {
                     return Err(syn::Error::new_spanned(
                         input,
-                        "rct derive macros can only supported structs named",
+                        "rct derive macros do not support unit structs",
                     ))
                 }
+                fields => vec![(None, fields)],
             },
-            SynData::Enum(_) => {
-                return Err(syn::Error::new_spanned(
-                    input,
-                    "rct derive macros can only supported structs",
-                ))
-            }
+            SynData::Enum(e) => e
+                .variants
+                .iter()
+                .map(|variant| (Some(variant), &variant.fields))
+                .collect(),
             SynData::Union(_) => {
                 return Err(syn::Error::new_spanned(
                     input,
-                    "rct derive macros can only supported structs",
+                    "rct derive macros do not support unions",
                 ))
             }
         };
 
-        let fields = fields.into_iter().collect::<Vec<&SynField>>();
+        let is_enum = matches!(input.data, SynData::Enum(_));
+        if let (false, Some(tag)) = (is_enum, &container.tag) {
+            return Err(syn::Error::new_spanned(
+                tag,
+                "#[table(tag)] can only be used on enums",
+            ));
+        }
 
         Ok(Self {
-            fields,
             struct_name,
+            container,
+            is_enum,
+            variants,
         })
     }
 
-    pub fn get_field(&self) -> Vec<Field> {
-        self.fields
-            .iter()
-            .enumerate()
-            .map(|(index, f)| Field::new(f, index).unwrap().unwrap())
-            .collect()
+    /// Returns the struct, or the variants of the enum, with the fields displayed in the table.
+    /// Skipped fields are left out.
+    pub fn get_variants(&self) -> Result<Vec<Variant>> {
+        let struct_name = self.struct_name;
+        let mut variants = vec![];
+        for (variant, fields) in &self.variants {
+            let mut displayed = vec![];
+            for (index, f) in fields.iter().enumerate() {
+                let field = Field::new(f, index, &self.container)?;
+                if field.flatten && variant.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        f,
+                        "#[table(flatten)] cannot be used in enums",
+                    ));
+                }
+                if !field.skip {
+                    displayed.push(field);
+                }
+            }
+            // the ordered fields come first, the others keep their position
+            displayed.sort_by_key(|field| field.order.unwrap_or(usize::MAX));
+
+            let (path, name) = match variant {
+                Some(variant) => {
+                    let ident = &variant.ident;
+                    let name = variant_name(variant)?;
+                    (quote!(#struct_name::#ident), Some(name))
+                }
+                None => (quote!(#struct_name), None),
+            };
+            variants.push(Variant {
+                path,
+                name,
+                fields: displayed,
+            });
+        }
+
+        Ok(variants)
+    }
+}
+
+impl Container {
+    pub fn new(attrs: &[Attribute]) -> Result<Self> {
+        let mut container = Container::default();
+        for meta in table_attrs(attrs)? {
+            match meta {
+                Meta::NameValue(meta) => match meta.path.get_ident() {
+                    Some(ident) if ident == "rename_all" => {
+                        let rule = lit_str(&meta, "rename_all")?;
+                        container.rename_all = Some(RenameRule::parse(&rule)?);
+                    }
+                    Some(ident) if ident == "tag" => {
+                        container.tag = Some(lit_str(&meta, "tag")?);
+                    }
+                    Some(ident) if ident == "border" => {
+                        let border = lit_str(&meta, "border")?;
+                        let style = match border.value().as_str() {
+                            "default" => quote!(Default),
+                            "simple" => quote!(Simple),
+                            "empty" => quote!(Empty),
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    border,
+                                    "Invalid border, expected one of: default, simple, empty",
+                                ))
+                            }
+                        };
+                        container.border =
+                            Some(quote!(::rct::styles::borders::BorderStyle::#style));
+                    }
+                    Some(ident) if ident == "layout" => {
+                        let layout = lit_str(&meta, "layout")?;
+                        let layout = match layout.value().as_str() {
+                            "horizontal" => quote!(Horizontal),
+                            "expanded" => quote!(Expanded),
+                            "auto" => quote!(auto()),
+                            "split" => quote!(split()),
+                            _ => {
+                                let message =
+                                    "Invalid layout, expected: horizontal, expanded, auto, split";
+                                return Err(syn::Error::new_spanned(layout, message));
+                            }
+                        };
+                        container.layout = Some(quote!(::rct::table::Layout::#layout));
+                    }
+                    Some(ident) if ident == "title" => {
+                        container.title = Some(lit_str(&meta, "title")?);
+                    }
+                    Some(ident) if ident == "row_style_with" => {
+                        let path = lit_str(&meta, "row_style_with")?.parse::<ExprPath>()?;
+                        container.row_style_with = Some(path);
+                    }
+                    _ if container.placeholders.parse(&meta)? => {}
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            meta,
+                            "Attributes should be of type: #[table(key = \"value\", ..)]",
+                        ))
+                    }
+                },
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        meta,
+                        "Attributes should be of type: #[table(key = \"value\", ..)]",
+                    ))
+                }
+            }
+        }
+
+        Ok(container)
     }
 }
 
 impl Field {
-    pub fn new(f: &SynField, index: usize) -> Result<Option<Self>> {
+    pub fn new(f: &SynField, index: usize, container: &Container) -> Result<Self> {
         let ident = f
             .ident
             .as_ref()
@@ -81,99 +264,395 @@ impl Field {
         let span = f.span();
 
         let mut field = get_fields(f)?;
+        // the header defaults to the name of the field, converted by `rename_all`
+        field.name = match &field.rename {
+            Some(rename) => rename.clone(),
+            None => {
+                let name = f
+                    .ident
+                    .as_ref()
+                    .map(|ident| ident.unraw().to_string())
+                    .unwrap_or_else(|| index.to_string());
+                let name = match &container.rename_all {
+                    Some(rule) => rule.apply(&name),
+                    None => name,
+                };
+                LitStr::new(&name, span)
+            }
+        };
+        field.placeholders = field.placeholders.or(&container.placeholders);
+        field.ty = f.ty.clone();
         field.ident = ident;
         field.span = Some(span);
 
-        Ok(Some(field))
+        Ok(field)
+    }
+}
+
+impl Placeholders {
+    /// Parses `none`, `bool` and `join` attributes, returns `false` for any other attribute.
+    fn parse(&mut self, meta: &MetaNameValue) -> Result<bool> {
+        match meta.path.get_ident() {
+            Some(ident) if ident == "none" => {
+                self.none = Some(lit_str(meta, "none")?);
+            }
+            Some(ident) if ident == "bool" => {
+                let value = lit_str(meta, "bool")?;
+                match value.value().split_once('/') {
+                    Some((yes, no)) => {
+                        let yes = LitStr::new(yes, value.span());
+                        let no = LitStr::new(no, value.span());
+                        self.bool = Some((yes, no));
+                    }
+                    None => {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "Invalid value for #[table(bool = \"true/false\")]",
+                        ))
+                    }
+                }
+            }
+            Some(ident) if ident == "join" => {
+                self.join = Some(lit_str(meta, "join")?);
+            }
+            _ => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    /// Uses the placeholders of `other` where none is set.
+    fn or(self, other: &Placeholders) -> Placeholders {
+        Placeholders {
+            none: self.none.or_else(|| other.none.clone()),
+            bool: self.bool.or_else(|| other.bool.clone()),
+            join: self.join.or_else(|| other.join.clone()),
+        }
+    }
+}
+
+/// Returns the value of the discriminant column of a variant, `rename` or its name.
+fn variant_name(variant: &SynVariant) -> Result<LitStr> {
+    let mut name = LitStr::new(&variant.ident.unraw().to_string(), variant.ident.span());
+    for meta in table_attrs(&variant.attrs)? {
+        match meta {
+            Meta::NameValue(meta) if meta.path.is_ident("rename") => {
+                name = lit_str(&meta, "rename")?;
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    "Only #[table(rename = \"value\")] can be used on variants",
+                ))
+            }
+        }
+    }
+
+    Ok(name)
+}
+
+/// Returns the arguments of every `#[table(...)]` attribute.
+fn table_attrs(attrs: &[Attribute]) -> Result<Vec<Meta>> {
+    let mut metas = vec![];
+    for attr in attrs.iter().filter(|a| a.path().is_ident("table")) {
+        let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        metas.extend(nested);
+    }
+
+    Ok(metas)
+}
+
+/// Returns the integer value of `#[table(name = 10)]`.
+fn lit_usize(meta: &MetaNameValue, name: &str) -> Result<usize> {
+    match &meta.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit_int),
+            ..
+        }) => lit_int.base10_parse(),
+        err => Err(syn::Error::new_spanned(
+            err,
+            format!("Invalid value for #[table({name} = 10)]"),
+        )),
     }
 }
 
-fn get_attrs<'a>(field: &'a SynField, attrs: &str) -> Option<&'a Attribute> {
-    field.attrs.iter().find(|a| a.path().is_ident(attrs))
+/// Returns the string value of `#[table(name = "value")]`.
+fn lit_str(meta: &MetaNameValue, name: &str) -> Result<LitStr> {
+    match &meta.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit_str),
+            ..
+        }) => Ok(lit_str.clone()),
+        err => Err(syn::Error::new_spanned(
+            err,
+            format!("Invalid value for #[table({name} = \"value\")]"),
+        )),
+    }
 }
 
 fn get_fields(f: &SynField) -> Result<Field> {
     let mut field = Field {
         ident: Default::default(),
-        name: None,
+        name: LitStr::new("", Span::call_site()),
+        rename: None,
         font: None,
         color: None,
         bg: None,
+        display_with: None,
+        format: None,
+        style_with: None,
+        placeholders: Placeholders::default(),
+        ty: Type::Verbatim(TokenStream::new()),
+        skip: false,
+        flatten: false,
+        prefix: None,
+        group: None,
+        nested: false,
+        order: None,
+        align: None,
+        width: None,
+        max_width: None,
+        overflow: None,
+        number: None,
+        freeze: false,
         span: None,
     };
-    if let Some(attr) = get_attrs(f, "table") {
-        let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
-        for meta in nested {
-            match meta {
-                Meta::NameValue(meta) => match meta.path.get_ident() {
-                    Some(ident) if ident == "rename" => {
-                        if let Expr::Lit(ExprLit { lit, .. }) = meta.value {
-                            field.name = Some(match lit {
-                                Lit::Str(lit_str) => lit_str,
-                                err => {
-                                    return Err(syn::Error::new_spanned(
-                                        err,
-                                        "Invalid value for #[table(rename = \"value\")]",
-                                    ))
-                                }
-                            });
-                        }
-                    }
-                    Some(ident) if ident == "color" => {
-                        if let Expr::Lit(ExprLit { lit, .. }) = meta.value {
-                            field.color = Some(match lit {
-                                Lit::Str(lit_str) => lit_str,
-                                err => {
-                                    return Err(syn::Error::new_spanned(
-                                        err,
-                                        "Invalid value for #[table(color = \"value\")]",
-                                    ))
-                                }
-                            });
-                        }
-                    }
-                    Some(ident) if ident == "bg" => {
-                        if let Expr::Lit(ExprLit { lit, .. }) = meta.value {
-                            field.bg = Some(match lit {
-                                Lit::Str(lit_str) => lit_str,
-                                err => {
-                                    return Err(syn::Error::new_spanned(
-                                        err,
-                                        "Invalid value for #[table(bg = \"value\")]",
-                                    ))
-                                }
-                            });
+    // the number format is made of a unit and of the settings given with it
+    let mut number = NumberAttributes::default();
+    for meta in table_attrs(&f.attrs)? {
+        match meta {
+            Meta::NameValue(meta) => match meta.path.get_ident() {
+                Some(ident) if ident == "rename" => {
+                    field.rename = Some(lit_str(&meta, "rename")?);
+                }
+                Some(ident) if ident == "color" => {
+                    field.color = Some(lit_str(&meta, "color")?);
+                }
+                Some(ident) if ident == "bg" => {
+                    field.bg = Some(lit_str(&meta, "bg")?);
+                }
+                Some(ident) if ident == "font" => {
+                    field.font = Some(lit_str(&meta, "font")?.parse::<Expr>()?);
+                }
+                Some(ident) if ident == "display_with" => {
+                    field.display_with = Some(lit_str(&meta, "display_with")?.parse::<ExprPath>()?);
+                }
+                Some(ident) if ident == "format" => {
+                    field.format = Some(lit_str(&meta, "format")?);
+                }
+                Some(ident) if ident == "style_with" => {
+                    field.style_with = Some(lit_str(&meta, "style_with")?.parse::<ExprPath>()?);
+                }
+                Some(ident) if ident == "prefix" => {
+                    field.prefix = Some(lit_str(&meta, "prefix")?);
+                }
+                Some(ident) if ident == "group" => {
+                    field.group = Some(lit_str(&meta, "group")?);
+                }
+                Some(ident) if ident == "order" => {
+                    field.order = Some(lit_usize(&meta, "order")?);
+                }
+                Some(ident) if ident == "width" => {
+                    field.width = Some(lit_usize(&meta, "width")?);
+                }
+                Some(ident) if ident == "max_width" => {
+                    field.max_width = Some(lit_usize(&meta, "max_width")?);
+                }
+                Some(ident)
+                    if ident == "currency" || ident == "currency_after" || ident == "si" =>
+                {
+                    let name = ident.to_string();
+                    let symbol = lit_str(&meta, &name)?;
+                    let constructor = Ident::new(&name, ident.span());
+                    number.unit(
+                        &meta.path,
+                        quote!(::rct::format::NumberFormat::#constructor(#symbol)),
+                    )?;
+                }
+                Some(ident) if ident == "decimals" => {
+                    number.decimals = Some(lit_usize(&meta, "decimals")?);
+                    number.attribute.get_or_insert("decimals".to_string());
+                }
+                Some(ident) if ident == "thousands" => {
+                    let separator = lit_str(&meta, "thousands")?;
+                    let value = separator.value();
+                    let mut chars = value.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) => number.thousands = Some(c),
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                separator,
+                                "Invalid separator, expected a single character",
+                            ))
                         }
                     }
-                    Some(ident) if ident == "font" => {
-                        if let Expr::Lit(ExprLit { lit, .. }) = &meta.value {
-                            field.font = Some(match lit {
-                                Lit::Str(lit_str) => lit_str.parse::<Expr>(),
-                                err => {
-                                    return Err(syn::Error::new_spanned(
-                                        err,
-                                        "Invalid value for #[table(font = \"value\")]",
-                                    ))
-                                }
-                            }?)
+                    number.attribute.get_or_insert("thousands".to_string());
+                }
+                Some(ident) if ident == "align" => {
+                    let align = lit_str(&meta, "align")?;
+                    let variant = match align.value().as_str() {
+                        "left" => quote!(Left),
+                        "center" => quote!(Center),
+                        "right" => quote!(Right),
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                align,
+                                "Invalid alignment, expected one of: left, center, right",
+                            ))
                         }
-                    }
-                    _ => {
-                        return Err(syn::Error::new_spanned(
-                            meta,
-                            "Attributes should be of type: #[table(key = \"value\", ..)]",
-                        ))
-                    }
-                },
+                    };
+                    field.align = Some(quote!(::rct::styles::align::Align::#variant));
+                }
+                _ if field.placeholders.parse(&meta)? => {}
                 _ => {
                     return Err(syn::Error::new_spanned(
                         meta,
                         "Attributes should be of type: #[table(key = \"value\", ..)]",
                     ))
                 }
-            };
+            },
+            Meta::Path(path) if path.is_ident("skip") => {
+                field.skip = true;
+            }
+            Meta::Path(path) if path.is_ident("flatten") => {
+                field.flatten = true;
+            }
+            Meta::Path(path) if path.is_ident("nested") => {
+                field.nested = true;
+            }
+            Meta::Path(path) if path.is_ident("freeze") => {
+                field.freeze = true;
+            }
+            Meta::Path(path) if path.is_ident("percent") || path.is_ident("bytes") => {
+                let constructor = path.get_ident().unwrap();
+                number.unit(&path, quote!(::rct::format::NumberFormat::#constructor()))?;
+            }
+            Meta::Path(path) if path.is_ident("wrap") || path.is_ident("truncate") => {
+                if field.overflow.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        path,
+                        "#[table(wrap)] cannot be used with #[table(truncate)]",
+                    ));
+                }
+                let overflow = match path.is_ident("wrap") {
+                    true => quote!(Wrap),
+                    false => quote!(Truncate),
+                };
+                field.overflow = Some(quote!(::rct::table::Overflow::#overflow));
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    "Attributes should be of type: #[table(key = \"value\", ..)]",
+                ))
+            }
+        };
+    }
+
+    if let (Some(_), Some(format)) = (&field.display_with, &field.format) {
+        return Err(syn::Error::new_spanned(
+            format,
+            "#[table(format)] cannot be used with #[table(display_with)]",
+        ));
+    }
+
+    if field.flatten || field.nested {
+        let attribute = if field.flatten { "flatten" } else { "nested" };
+        let conflict = match &field {
+            Field {
+                flatten: true,
+                nested: true,
+                ..
+            } => Some("nested"),
+            Field {
+                display_with: Some(_),
+                ..
+            } => Some("display_with"),
+            Field {
+                format: Some(_), ..
+            } => Some("format"),
+            Field {
+                flatten: true,
+                color: Some(_),
+                ..
+            } => Some("color"),
+            Field {
+                flatten: true,
+                bg: Some(_),
+                ..
+            } => Some("bg"),
+            Field {
+                flatten: true,
+                font: Some(_),
+                ..
+            } => Some("font"),
+            Field {
+                flatten: true,
+                rename: Some(_),
+                ..
+            } => Some("rename"),
+            _ => None,
+        };
+        if let Some(conflict) = conflict.or(number.attribute.as_deref()) {
+            return Err(syn::Error::new_spanned(
+                f,
+                format!("#[table({conflict})] cannot be used with #[table({attribute})]"),
+            ));
+        }
+    }
+
+    field.number = number.format();
+
+    if !field.flatten {
+        if let Some(attribute) = field.prefix.as_ref().or(field.group.as_ref()) {
+            return Err(syn::Error::new_spanned(
+                attribute,
+                "#[table(prefix)] and #[table(group)] can only be used with #[table(flatten)]",
+            ));
         }
     }
 
     Ok(field)
 }
+
+/// The attributes of a field choosing the format of its typed numbers.
+#[derive(Default)]
+struct NumberAttributes {
+    /// Constructor of the format, `NumberFormat::new()` when only the settings are given.
+    unit: Option<TokenStream>,
+    decimals: Option<usize>,
+    thousands: Option<char>,
+    /// Name of the first of these attributes, for the errors.
+    attribute: Option<String>,
+}
+
+impl NumberAttributes {
+    fn unit(&mut self, path: &syn::Path, unit: TokenStream) -> Result<()> {
+        if self.unit.is_some() {
+            return Err(syn::Error::new_spanned(
+                path,
+                "Only one of currency, currency_after, percent, bytes and si can be used",
+            ));
+        }
+        self.unit = Some(unit);
+        self.attribute
+            .get_or_insert_with(|| path.to_token_stream().to_string());
+
+        Ok(())
+    }
+
+    /// Returns the expression building the format, if any attribute was given.
+    fn format(&self) -> Option<TokenStream> {
+        self.attribute.as_ref()?;
+        let unit = self
+            .unit
+            .clone()
+            .unwrap_or_else(|| quote!(::rct::format::NumberFormat::new()));
+        let decimals = self.decimals.map(|decimals| quote!(.decimals(#decimals)));
+        let thousands = self
+            .thousands
+            .map(|separator| quote!(.thousands(#separator)));
+
+        Some(quote!(#unit #decimals #thousands))
+    }
+}
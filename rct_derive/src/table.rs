@@ -1,116 +1,577 @@
-use proc_macro2::TokenStream;
-use quote::{quote, quote_spanned};
-use syn::{parse_quote, DeriveInput, GenericParam, Generics};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse_quote, DeriveInput, GenericArgument, Generics, LitStr, PathArguments, PathSegment, Type,
+    WherePredicate,
+};
 
-use crate::{fields::Data, utils::new_generic};
+use crate::fields::{Data, Field, Variant};
 
 pub fn to_table(input: DeriveInput) -> syn::Result<TokenStream> {
     let data = Data::new(&input)?;
     let struct_name = data.struct_name;
-    let fields = data.get_field();
+    let variants = data.get_variants()?;
 
-    // Add a bound `T: ::std::fmt::Display` to every type parameter T.
-    let (generics, new_generic) = new_generic(input.to_owned().generics);
-    let generics = add_trait_bounds(generics, &new_generic, struct_name);
-    let (impl_generics, _ty_generics, where_clause) = generics.split_for_impl();
+    // Bound the types of the fields using the type parameters, e.g. `T: ::std::fmt::Display`.
+    let generics = add_trait_bounds(input.generics.clone(), &variants);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let mut fields_name = vec![];
-    let mut fields_rows = vec![];
+    let Columns {
+        headers,
+        groups,
+        arms,
+        settings,
+    } = match data.is_enum {
+        true => enum_columns(&data, &variants),
+        false => struct_columns(&variants[0]),
+    };
+
+    // the groups are only displayed when a flattened field has one
+    let has_groups = variants
+        .iter()
+        .flat_map(|variant| &variant.fields)
+        .any(|field| field.group.is_some());
+    let groups = has_groups.then(|| {
+        quote! {
+            fn groups() -> ::std::vec::Vec<(::std::string::String, usize)> {
+                let mut groups = ::std::vec::Vec::new();
+                #(#groups)*
+                groups
+            }
+        }
+    });
 
-    for field in fields {
-        let name = field.name.unwrap();
-        fields_name.push(quote!(#name.cell()));
+    let border = data
+        .container
+        .border
+        .as_ref()
+        .map(|border| quote!(table.border(#border);));
+    let title = data
+        .container
+        .title
+        .as_ref()
+        .map(|title| quote!(table.title(::rct::ICell::cell(#title));));
+    let layout = data
+        .container
+        .layout
+        .as_ref()
+        .map(|layout| quote!(table.layout(#layout);));
+    let configured =
+        border.is_some() || title.is_some() || layout.is_some() || !settings.is_empty();
+    let configure = configured.then(|| {
+        quote! {
+            fn configure(table: &mut ::rct::Table) {
+                #border
+                #title
+                #layout
+                #(#settings)*
+            }
+        }
+    });
 
-        let ident = field.ident;
-        let color = field.color;
-        let bg = field.bg;
-        let font = field.font;
-        let span = field.span.unwrap();
+    let row_style = data.container.row_style_with.as_ref().map(|path| {
+        quote! {
+            let style = #path(self);
+            let cells = cells
+                .iter()
+                .map(|cell| ::rct::styles::color::Style::apply(&style, cell))
+                .collect::<::std::vec::Vec<_>>();
+        }
+    });
 
-        let mut row = quote_spanned! {span=>
-            field. #ident.cell()
-        };
+    // an enum without variants has no value to match
+    let cells = match arms.is_empty() {
+        true => quote!(match *self {}),
+        false => quote!(match self { #(#arms)* }),
+    };
+
+    let expanded = quote! {
+        #[automatically_derived]
+        impl #impl_generics ::rct::ToTable for #struct_name #ty_generics #where_clause {
+            fn headers() -> ::std::vec::Vec<::std::string::String> {
+                let mut headers = ::std::vec::Vec::new();
+                #(#headers)*
+                headers
+            }
+
+            #groups
+
+            fn to_row(&self) -> ::rct::Row {
+                let mut cells = ::std::vec::Vec::new();
+                #cells
+                #row_style
+                ::rct::Row::from(cells)
+            }
 
-        if let Some(color) = color {
-            row = quote_spanned! {span=>
-                #row .color(#color)
-            };
+            #configure
         }
+    };
+
+    Ok(expanded)
+}
+
+/// Statements building the parts of a table of a struct or an enum.
+struct Columns {
+    /// Statements pushing the headers in `headers`.
+    headers: Vec<TokenStream>,
+    /// Statements pushing the groups in `groups`.
+    groups: Vec<TokenStream>,
+    /// Arms of the match pushing the cells of a value in `cells`.
+    arms: Vec<TokenStream>,
+    /// Statements applying the settings of the columns to `table`.
+    settings: Vec<TokenStream>,
+}
+
+/// Returns the statements building the table of a struct.
+///
+/// Flattened fields push the headers and the cells of their own type, under a single group.
+fn struct_columns(variant: &Variant) -> Columns {
+    let mut headers = vec![];
+    let mut groups = vec![];
+    let mut cells = vec![];
+    let mut settings = vec![];
+    let mut idents = vec![];
+    let mut bindings = vec![];
+    // the index of the column depends on the number of columns of the flattened fields
+    let mut column = quote!(0);
 
-        if let Some(bg) = bg {
-            row = quote_spanned! {span=>
-                #row .bg(#bg)
-            };
+    for (index, field) in variant.fields.iter().enumerate() {
+        let binding = format_ident!("__rct_field_{}", index);
+        if field.flatten {
+            let ty = strip_references(&field.ty);
+            let prefix = field.prefix.as_ref().map(LitStr::value).unwrap_or_default();
+            headers.push(quote! {
+                headers.extend(
+                    <#ty as ::rct::ToTable>::headers()
+                        .into_iter()
+                        .map(|header| ::std::format!("{}{}", #prefix, header)),
+                );
+            });
+            groups.push(match &field.group {
+                Some(group) => quote! {
+                    groups.push((::std::string::String::from(#group), <#ty as ::rct::ToTable>::headers().len()));
+                },
+                None => quote! {
+                    groups.extend(
+                        <#ty as ::rct::ToTable>::headers()
+                            .into_iter()
+                            .map(|_| (::std::string::String::new(), 1)),
+                    );
+                },
+            });
+            cells.push(quote!(cells.extend(::rct::ToTable::to_row(#binding).cells);));
+            column = quote!(#column + <#ty as ::rct::ToTable>::headers().len());
+        } else {
+            let name = &field.name;
+            headers.push(quote!(headers.push(::std::string::String::from(#name));));
+            groups.push(quote!(groups.push((::std::string::String::new(), 1));));
+            let cell = field_cell(field, quote!(#binding));
+            cells.push(quote!(cells.push(#cell);));
+            settings.extend(column_settings(field, &column));
+            column = quote!(#column + 1);
         }
+        idents.push(&field.ident);
+        bindings.push(binding);
+    }
+
+    let path = &variant.path;
+    let arm = quote! {
+        #path { #(#idents: #bindings,)* .. } => { #(#cells)* }
+    };
 
-        if let Some(font) = font {
-            row = quote_spanned! {span=>
-                #row .font(#font)
-            };
+    Columns {
+        headers,
+        groups,
+        arms: vec![arm],
+        settings,
+    }
+}
+
+/// Returns the statements building the table of an enum.
+///
+/// The columns are the discriminant followed by the fields of every variant, merged by header
+/// and sorted by the order of their first field.
+fn enum_columns(data: &Data, variants: &[Variant]) -> Columns {
+    let mut fields: Vec<&Field> = vec![];
+    for field in variants.iter().flat_map(|variant| &variant.fields) {
+        if !fields
+            .iter()
+            .any(|first| first.name.value() == field.name.value())
+        {
+            fields.push(field);
         }
+    }
+    fields.sort_by_key(|field| field.order.unwrap_or(usize::MAX));
 
-        fields_rows.push(row);
+    let tag = data.container.tag.clone();
+    let mut columns = vec![tag.unwrap_or_else(|| LitStr::new("variant", Span::call_site()))];
+    columns.extend(fields.iter().map(|field| field.name.clone()));
+    // the discriminant column is left out, a field may have its name
+    let field_column = |field: &Field| {
+        1 + fields
+            .iter()
+            .position(|first| first.name.value() == field.name.value())
+            .unwrap()
+    };
+    let mut settings = vec![];
+    for variant in variants {
+        for field in &variant.fields {
+            let column = field_column(field);
+            settings.extend(column_settings(field, &quote!(#column)));
+        }
     }
 
-    let expanded = quote! {
-        use rct::ICell;
+    let headers = columns
+        .iter()
+        .map(|name| quote!(headers.push(::std::string::String::from(#name));))
+        .collect();
+    let groups = columns
+        .iter()
+        .map(|_| quote!(groups.push((::std::string::String::new(), 1));))
+        .collect();
 
-        pub trait Tabler {
-            fn to_table(self) -> rct::Table;
+    let mut arms = vec![];
+    for variant in variants {
+        let mut cells = vec![None; columns.len()];
+        cells[0] = variant
+            .name
+            .as_ref()
+            .map(|name| quote!(::rct::ICell::cell(#name)));
+
+        let mut idents = vec![];
+        let mut bindings = vec![];
+        for (index, field) in variant.fields.iter().enumerate() {
+            let binding = format_ident!("__rct_field_{}", index);
+            let column = field_column(field);
+            cells[column] = Some(field_cell(field, quote!(#binding)));
+            idents.push(&field.ident);
+            bindings.push(binding);
         }
 
-        #[automatically_derived]
-        impl #impl_generics Tabler for #new_generic #where_clause
-        {
-            fn to_table(self) -> rct::Table {
-                let mut table = rct::Table::new();
-                let header = ::std::vec![#(#fields_name,)*];
-                table.add_header(header);
-                for field in self {
-                    let rows = ::std::vec![#(#fields_rows,)*];
-                    table.add_row(rows);
-                }
+        let path = &variant.path;
+        let cells = cells
+            .into_iter()
+            .map(|cell| cell.unwrap_or_else(|| quote!(::rct::ICell::cell(""))));
+        arms.push(quote! {
+            #path { #(#idents: #bindings,)* .. } => { #(cells.push(#cells);)* }
+        });
+    }
+
+    Columns {
+        headers,
+        groups,
+        arms,
+        settings,
+    }
+}
+
+/// Returns the statements applying the settings of a field to its column at `index`.
+fn column_settings(field: &Field, index: &TokenStream) -> Vec<TokenStream> {
+    let mut settings = vec![];
+    if let Some(align) = &field.align {
+        settings.push(quote!(table.align_column(#index, #align);));
+    }
+    if let Some(width) = &field.width {
+        settings.push(quote!(table.width_column(#index, #width);));
+    }
+    if let Some(max_width) = &field.max_width {
+        settings.push(quote!(table.max_width_column(#index, #max_width);));
+    }
+    if let Some(overflow) = &field.overflow {
+        settings.push(quote!(table.overflow_column(#index, #overflow);));
+    }
+    if let Some(number) = &field.number {
+        settings.push(quote!(table.format_column(#index, #number);));
+    }
+    if field.freeze {
+        settings.push(quote!(table.freeze_column(#index);));
+    }
 
-                table
+    settings
+}
+
+/// Returns the cell of a field, `value` being a reference to it.
+fn field_cell(field: &Field, value: TokenStream) -> TokenStream {
+    let span = field.span.unwrap();
+
+    let mut cell = match &field.display_with {
+        Some(display_with) => quote_spanned! {span=>
+            ::rct::ICell::cell(#display_with(#value))
+        },
+        None if field.nested => nested_table(&field.ty, value.clone()),
+        None => {
+            let value = display_value(&field.ty, value.clone(), field);
+            quote_spanned! {span=>
+                ::rct::ICell::cell(#value)
             }
         }
     };
 
-    Ok(expanded)
+    if let Some(typed) = typed_value(&field.ty, value.clone()).filter(|_| !field.nested) {
+        cell = quote_spanned! {span=>
+            ::rct::Cell::with_value(&#cell, #typed)
+        };
+    }
+
+    // the style computed from the value takes precedence over the static colors
+    if let Some(style_with) = &field.style_with {
+        cell = quote_spanned! {span=>
+            ::rct::styles::color::Style::apply(&#style_with(#value), &#cell)
+        };
+    }
+
+    if let Some(color) = &field.color {
+        cell = quote_spanned! {span=>
+            ::rct::styles::color::Colorizer::color(&#cell, #color)
+        };
+    }
+
+    if let Some(bg) = &field.bg {
+        cell = quote_spanned! {span=>
+            ::rct::styles::color::Colorizer::bg(&#cell, #bg)
+        };
+    }
+
+    if let Some(font) = &field.font {
+        cell = quote_spanned! {span=>
+            ::rct::styles::color::Colorizer::font(&#cell, #font)
+        };
+    }
+
+    cell
+}
+
+/// Returns the cell of a nested field, a table with a row for the value,
+/// or for every item of a collection.
+fn nested_table(ty: &Type, value: TokenStream) -> TokenStream {
+    let items = match collection_item(strip_references(ty)) {
+        Some(_) => quote!((#value).iter()),
+        None => quote!(::std::iter::once(#value)),
+    };
+
+    quote! {
+        ::rct::ICell::cell(::rct::IntoTable::to_table(#items).to_string())
+    }
+}
+
+/// Returns an expression displaying `value`, a reference to a value of type `ty`.
+///
+/// `Option`, `bool` and collections are recognised and displayed with the placeholders
+/// of the field, other values with their `Display` implementation or the field format.
+fn display_value(ty: &Type, value: TokenStream, field: &Field) -> TokenStream {
+    let placeholders = &field.placeholders;
+    match ty {
+        Type::Reference(reference) => display_value(&reference.elem, quote!(*#value), field),
+        Type::Paren(paren) => display_value(&paren.elem, value, field),
+        Type::Group(group) => display_value(&group.elem, value, field),
+        ty if collection_item(ty).is_some() => {
+            display_items(collection_item(ty).unwrap(), value, field)
+        }
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last().unwrap();
+            match (
+                segment.ident.to_string().as_str(),
+                generic_argument(segment),
+            ) {
+                ("Option", Some(inner)) => {
+                    let none = placeholders
+                        .none
+                        .as_ref()
+                        .map(LitStr::value)
+                        .unwrap_or_else(|| "-".to_string());
+                    let inner = display_value(inner, quote!(value), field);
+                    quote! {
+                        match #value {
+                            ::std::option::Option::Some(value) => ::std::string::ToString::to_string(&#inner),
+                            ::std::option::Option::None => ::std::string::String::from(#none),
+                        }
+                    }
+                }
+                ("bool", None) => {
+                    let (yes, no) = match &placeholders.bool {
+                        Some((yes, no)) => (yes.value(), no.value()),
+                        None => ("✓".to_string(), "✗".to_string()),
+                    };
+                    quote! {
+                        if *#value { #yes } else { #no }
+                    }
+                }
+                _ => display_leaf(value, field),
+            }
+        }
+        _ => display_leaf(value, field),
+    }
+}
+
+/// Returns an expression building the typed value of `value`, a reference to a value of
+/// type `ty`, when it is a number, a `bool`, a `SystemTime` or an `Option` of one of them.
+fn typed_value(ty: &Type, value: TokenStream) -> Option<TokenStream> {
+    match ty {
+        Type::Reference(reference) => typed_value(&reference.elem, quote!(*#value)),
+        Type::Paren(paren) => typed_value(&paren.elem, value),
+        Type::Group(group) => typed_value(&group.elem, value),
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+            match (
+                segment.ident.to_string().as_str(),
+                generic_argument(segment),
+            ) {
+                ("Option", Some(inner)) => {
+                    let inner = typed_value(inner, quote!(value))?;
+                    Some(quote! {
+                        match #value {
+                            ::std::option::Option::Some(value) => #inner,
+                            ::std::option::Option::None => ::rct::value::Value::Null,
+                        }
+                    })
+                }
+                (
+                    "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+                    | "u128" | "usize" | "f32" | "f64" | "bool" | "SystemTime",
+                    None,
+                ) => Some(quote!(::rct::value::Value::from(*#value))),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns an expression joining the items of a collection.
+fn display_items(item: &Type, value: TokenStream, field: &Field) -> TokenStream {
+    let join = field
+        .placeholders
+        .join
+        .as_ref()
+        .map(LitStr::value)
+        .unwrap_or_else(|| ", ".to_string());
+    let item = display_value(item, quote!(item), field);
+    quote! {
+        (#value)
+            .iter()
+            .map(|item| ::std::string::ToString::to_string(&#item))
+            .collect::<::std::vec::Vec<_>>()
+            .join(#join)
+    }
 }
 
-// Add a bound `T: ::std::fmt::Display` to every type parameter T.
-fn add_trait_bounds(
-    mut generics: Generics,
-    new_generic: &syn::Ident,
-    struct_name: &syn::Ident,
-) -> Generics {
-    let mut type_params = generics
-        .to_owned()
-        .params
-        .into_iter()
-        .filter_map(|param| {
-            if let GenericParam::Type(syn::TypeParam { ident, .. }) = param {
-                Some(quote!(#ident))
-            } else {
-                None
+/// Returns an expression displaying a value with the field format, or with `Display`.
+fn display_leaf(value: TokenStream, field: &Field) -> TokenStream {
+    match &field.format {
+        Some(format) => quote!(::std::format!(#format, #value)),
+        None => value,
+    }
+}
+
+/// Returns the type of the items of a collection (`Vec`, `HashSet`, arrays, slices, ...).
+fn collection_item(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Array(array) => Some(&array.elem),
+        Type::Slice(slice) => Some(&slice.elem),
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+            match segment.ident.to_string().as_str() {
+                "Vec" | "VecDeque" | "LinkedList" | "HashSet" | "BTreeSet" => {
+                    generic_argument(segment)
+                }
+                _ => None,
             }
-        })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the type behind references and parentheses.
+fn strip_references(ty: &Type) -> &Type {
+    match ty {
+        Type::Reference(reference) => strip_references(&reference.elem),
+        Type::Paren(paren) => strip_references(&paren.elem),
+        Type::Group(group) => strip_references(&group.elem),
+        ty => ty,
+    }
+}
+
+/// Returns the only generic argument of a type like `Option<T>`.
+fn generic_argument(segment: &PathSegment) -> Option<&Type> {
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+            match arguments.args.first() {
+                Some(GenericArgument::Type(ty)) => Some(ty),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Adds the bounds needed to display the fields whose type uses a type parameter:
+/// `Display` for displayed values, `ToTable` for flattened and nested values.
+fn add_trait_bounds(mut generics: Generics, variants: &[Variant]) -> Generics {
+    let params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
         .collect::<Vec<_>>();
-    type_params.pop();
-
-    for param in &mut generics.params {
-        if let GenericParam::Type(ref mut type_param) = *param {
-            // Do not add bound for new generic types
-            if *new_generic == type_param.ident {
-                type_param
-                    .bounds
-                    .push(parse_quote!(IntoIterator<Item = #struct_name<#(#type_params,)*>>));
-                continue;
+    if params.is_empty() {
+        return generics;
+    }
+
+    let mut predicates: Vec<WherePredicate> = vec![];
+    for field in variants.iter().flat_map(|variant| &variant.fields) {
+        let mut bounds = vec![];
+        if field.flatten {
+            let ty = strip_references(&field.ty);
+            bounds.push(quote!(#ty: ::rct::ToTable));
+        } else if field.nested {
+            let ty = strip_references(&field.ty);
+            let ty = collection_item(ty).map(strip_references).unwrap_or(ty);
+            bounds.push(quote!(#ty: ::rct::ToTable));
+        } else if field.display_with.is_none() && field.format.is_none() {
+            for ty in displayed_types(&field.ty) {
+                bounds.push(quote!(#ty: ::std::fmt::Display));
+            }
+        }
+
+        for bound in bounds {
+            if mentions(bound.clone(), &params) {
+                let predicate = parse_quote!(#bound);
+                if !predicates.contains(&predicate) {
+                    predicates.push(predicate);
+                }
             }
-            type_param.bounds.push(parse_quote!(::std::fmt::Display));
         }
     }
 
+    generics.make_where_clause().predicates.extend(predicates);
     generics
 }
+
+/// Returns the types displayed with `Display` by [`display_value`] for a field of type `ty`.
+fn displayed_types(ty: &Type) -> Vec<&Type> {
+    match ty {
+        Type::Reference(_) | Type::Paren(_) | Type::Group(_) => {
+            displayed_types(strip_references(ty))
+        }
+        ty if collection_item(ty).is_some() => displayed_types(collection_item(ty).unwrap()),
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last().unwrap();
+            match (
+                segment.ident.to_string().as_str(),
+                generic_argument(segment),
+            ) {
+                ("Option", Some(inner)) => displayed_types(inner),
+                ("bool", None) => vec![],
+                _ => vec![ty],
+            }
+        }
+        ty => vec![ty],
+    }
+}
+
+/// Returns `true` if the tokens use one of the type parameters.
+fn mentions(tokens: TokenStream, params: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => params.contains(&ident),
+        TokenTree::Group(group) => mentions(group.stream(), params),
+        _ => false,
+    })
+}
@@ -1,43 +1,113 @@
-fn generate_random_name() -> syn::Ident {
-    // Generate a random name using a combination of lowercase letters
-    let mut name = String::new();
-    let letters = "abcdefghijklmnopqrstuvwxyz".to_uppercase();
-    for _ in 0..8 {
-        let random_index = (letters.len() as f32 * rand::random::<f32>()) as usize;
-        name.push(letters.chars().nth(random_index).unwrap());
-    }
-    syn::Ident::new(&name, proc_macro2::Span::call_site())
+use syn::{LitStr, Result};
+
+/// Case conversion of the field names used as headers, chosen with `#[table(rename_all = "...")]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+    Title,
+    Sentence,
 }
 
-pub fn new_generic(mut generics: syn::Generics) -> (syn::Generics, syn::Ident) {
-    #[allow(unused)]
-    let mut new_generic_param = None;
-    let ident: syn::Ident;
-    loop {
-        // Generate a random name for GenericParam
-        let name = generate_random_name();
-
-        // Check if the name already exists in Generics
-        let exists = generics.params.iter().any(|param| {
-            if let syn::GenericParam::Type(type_param) = param {
-                type_param.ident == name
-            } else {
-                false
-            }
-        });
+const RENAME_RULES: &[(&str, RenameRule)] = &[
+    ("lowercase", RenameRule::Lower),
+    ("UPPERCASE", RenameRule::Upper),
+    ("PascalCase", RenameRule::Pascal),
+    ("camelCase", RenameRule::Camel),
+    ("snake_case", RenameRule::Snake),
+    ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnake),
+    ("kebab-case", RenameRule::Kebab),
+    ("SCREAMING-KEBAB-CASE", RenameRule::ScreamingKebab),
+    ("Title Case", RenameRule::Title),
+    ("Sentence case", RenameRule::Sentence),
+];
 
-        // If name is not exists, create new GenericParam
-        if !exists {
-            new_generic_param = Some(syn::parse_quote! { #name });
-            ident = name;
-            break;
+impl RenameRule {
+    pub fn parse(rule: &LitStr) -> Result<Self> {
+        let value = rule.value();
+        RENAME_RULES
+            .iter()
+            .find(|(name, _)| *name == value)
+            .map(|(_, rule)| *rule)
+            .ok_or_else(|| {
+                let names: Vec<_> = RENAME_RULES.iter().map(|(name, _)| *name).collect();
+                syn::Error::new_spanned(
+                    rule,
+                    format!(
+                        "Invalid value for #[table(rename_all = \"value\")], expected one of: {}",
+                        names.join(", ")
+                    ),
+                )
+            })
+    }
+
+    /// Converts the name of a field, e.g. `created_at` to `Created At` for `Title Case`.
+    pub fn apply(&self, name: &str) -> String {
+        let words = words(name);
+        let capitalized = || {
+            words
+                .iter()
+                .map(|word| capitalize(word))
+                .collect::<Vec<_>>()
+        };
+
+        match self {
+            RenameRule::Lower => words.concat(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+            RenameRule::Pascal => capitalized().concat(),
+            RenameRule::Camel => {
+                let mut name = capitalized().concat();
+                if let Some(first) = words.first() {
+                    name.replace_range(..first.len(), first);
+                }
+                name
+            }
+            RenameRule::Snake => words.join("_"),
+            RenameRule::ScreamingSnake => words.join("_").to_uppercase(),
+            RenameRule::Kebab => words.join("-"),
+            RenameRule::ScreamingKebab => words.join("-").to_uppercase(),
+            RenameRule::Title => capitalized().join(" "),
+            RenameRule::Sentence => {
+                let mut words = words.clone();
+                if let Some(first) = words.first_mut() {
+                    *first = capitalize(first);
+                }
+                words.join(" ")
+            }
         }
     }
+}
 
-    // Add the new GenericParam in Generics
-    if let Some(param) = new_generic_param {
-        generics.params.push(param);
+/// Splits a name in lowercase words, on `_` and on the uppercase letters of `camelCase` names.
+fn words(name: &str) -> Vec<String> {
+    let mut words: Vec<String> = vec![];
+    let mut previous_lowercase = false;
+    for c in name.chars() {
+        if c == '_' || c == '-' {
+            words.push(String::new());
+            previous_lowercase = false;
+            continue;
+        }
+        if words.is_empty() || (c.is_uppercase() && previous_lowercase) {
+            words.push(String::new());
+        }
+        previous_lowercase = c.is_lowercase() || c.is_ascii_digit();
+        words.last_mut().unwrap().extend(c.to_lowercase());
     }
 
-    (generics, ident)
+    words.into_iter().filter(|word| !word.is_empty()).collect()
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
 }
@@ -1,4 +1,4 @@
-use rct::ToTable;
+use rct::{IntoTable, ToTable};
 
 #[derive(ToTable)]
 struct Movies<T, S> {
@@ -37,6 +37,806 @@ fn struct_test() {
 ║ 2  │ Spider-man │ 18.8    ║
 ╚════╧════════════╧═════════╝";
 
-assert_eq!("\n".to_string() + &table.to_string(), expected)
+    assert_eq!("\n".to_string() + &table.to_string(), expected)
+}
+
+mod rename_all {
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    #[table(rename_all = "Title Case")]
+    struct Order {
+        order_id: u32,
+        #[table(rename = "Customer")]
+        customer_name: String,
+        #[table(skip)]
+        #[allow(dead_code)]
+        internal_note: String,
+        total_price: f32,
+    }
+
+    #[test]
+    fn struct_rename_all_skip_test() {
+        let orders = vec![Order {
+            order_id: 1,
+            customer_name: "Harry".to_string(),
+            internal_note: "do not display".to_string(),
+            total_price: 14.87,
+        }];
+
+        let table = orders.into_iter().to_table();
+
+        let expected = "
+╔══════════╤══════════╤═════════════╗
+║ Order Id │ Customer │ Total Price ║
+╟──────────┼──────────┼─────────────╢
+║ 1        │ Harry    │ 14.87       ║
+╚══════════╧══════════╧═════════════╝";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected)
+    }
+}
+
+mod display_with {
+    use rct::{IntoTable, ToTable};
+
+    fn or_dash(value: &Option<u32>) -> String {
+        value.map_or_else(|| "-".to_string(), |value| value.to_string())
+    }
+
+    fn tags(values: &[String]) -> String {
+        values.join(", ")
+    }
+
+    #[derive(ToTable)]
+    struct Product {
+        #[table(rename = "Stock", display_with = "or_dash")]
+        stock: Option<u32>,
+        #[table(rename = "Tags", display_with = "tags")]
+        tags: Vec<String>,
+        #[table(rename = "Price", format = "{:.2} €")]
+        price: f64,
+    }
+
+    #[test]
+    fn struct_display_with_format_test() {
+        let products = vec![
+            Product {
+                stock: Some(3),
+                tags: vec!["book".to_string(), "kids".to_string()],
+                price: 14.8,
+            },
+            Product {
+                stock: None,
+                tags: vec![],
+                price: 8.0,
+            },
+        ];
+
+        let table = products.into_iter().to_table();
+
+        let expected = "
+╔═══════╤════════════╤═════════╗
+║ Stock │ Tags       │ Price   ║
+╟───────┼────────────┼─────────╢
+║ 3     │ book, kids │ 14.80 € ║
+╟───────┼────────────┼─────────╢
+║ -     │            │ 8.00 €  ║
+╚═══════╧════════════╧═════════╝";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected)
+    }
+}
+
+mod placeholders {
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    #[table(none = "n/a", bool = "yes/no")]
+    struct User {
+        name: &'static str,
+        email: Option<String>,
+        active: bool,
+        #[table(bool = "✓/✗")]
+        admin: bool,
+        #[table(join = "\n")]
+        roles: Vec<&'static str>,
+        #[table(none = "", format = "{:.1}")]
+        score: Option<f64>,
+    }
+
+    #[test]
+    fn struct_placeholders_test() {
+        let users = vec![
+            User {
+                name: "Harry",
+                email: Some("harry@hogwarts.uk".to_string()),
+                active: true,
+                admin: false,
+                roles: vec!["student", "seeker"],
+                score: Some(9.25),
+            },
+            User {
+                name: "Ron",
+                email: None,
+                active: false,
+                admin: true,
+                roles: vec![],
+                score: None,
+            },
+        ];
+
+        let table = users.into_iter().to_table();
+
+        let expected = "
+╔═══════╤═══════════════════╤════════╤═══════╤═════════╤═══════╗
+║ name  │ email             │ active │ admin │ roles   │ score ║
+╟───────┼───────────────────┼────────┼───────┼─────────┼───────╢
+║ Harry │ harry@hogwarts.uk │ yes    │ ✗     │ student │ 9.2   ║
+║       │                   │        │       │ seeker  │       ║
+╟───────┼───────────────────┼────────┼───────┼─────────┼───────╢
+║ Ron   │ n/a               │ no     │ ✓     │         │       ║
+╚═══════╧═══════════════════╧════════╧═══════╧═════════╧═══════╝";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected)
+    }
+}
+
+mod tuple_struct {
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    struct Point(i32, #[table(rename = "y")] i32);
+
+    #[test]
+    fn tuple_struct_test() {
+        let points = vec![Point(1, 2), Point(-3, 4)];
+
+        let table = points.into_iter().to_table();
+
+        let expected = "
+╔════╤═══╗
+║ 0  │ y ║
+╟────┼───╢
+║ 1  │ 2 ║
+╟────┼───╢
+║ -3 │ 4 ║
+╚════╧═══╝";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected)
+    }
+}
+
+mod enums {
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    #[table(tag = "Kind")]
+    enum Shape {
+        Circle {
+            radius: f32,
+        },
+        #[table(rename = "Rect")]
+        Rectangle {
+            width: f32,
+            height: f32,
+        },
+        Square {
+            width: f32,
+        },
+        Empty,
+    }
+
+    #[test]
+    fn enum_test() {
+        let shapes = vec![
+            Shape::Circle { radius: 1.5 },
+            Shape::Rectangle {
+                width: 2.0,
+                height: 3.0,
+            },
+            Shape::Square { width: 4.0 },
+            Shape::Empty,
+        ];
+
+        let table = shapes.into_iter().to_table();
+
+        let expected = "
+╔════════╤════════╤═══════╤════════╗
+║ Kind   │ radius │ width │ height ║
+╟────────┼────────┼───────┼────────╢
+║ Circle │ 1.5    │       │        ║
+╟────────┼────────┼───────┼────────╢
+║ Rect   │        │ 2     │ 3      ║
+╟────────┼────────┼───────┼────────╢
+║ Square │        │ 4     │        ║
+╟────────┼────────┼───────┼────────╢
+║ Empty  │        │       │        ║
+╚════════╧════════╧═══════╧════════╝";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected)
+    }
+}
+
+mod enum_tag_collision {
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    enum Event {
+        Start { variant: String, at: u32 },
+        Stop { code: i32 },
+    }
+
+    #[test]
+    fn enum_field_named_like_tag_test() {
+        let events = vec![
+            Event::Start {
+                variant: "x".to_string(),
+                at: 1,
+            },
+            Event::Stop { code: 2 },
+        ];
+
+        let table = events.into_iter().to_table();
+
+        let expected = "
+╔═════════╤═════════╤════╤══════╗
+║ variant │ variant │ at │ code ║
+╟─────────┼─────────┼────┼──────╢
+║ Start   │ x       │ 1  │      ║
+╟─────────┼─────────┼────┼──────╢
+║ Stop    │         │    │ 2    ║
+╚═════════╧═════════╧════╧══════╝";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected)
+    }
+}
+
+mod enum_newtypes {
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    enum Id {
+        Number(u32),
+        Name(String),
+    }
+
+    #[test]
+    fn enum_newtype_variants_test() {
+        let ids = vec![Id::Number(7), Id::Name("Harry".to_string())];
+
+        let table = ids.into_iter().to_table();
+
+        let expected = "
+╔═════════╤═══════╗
+║ variant │ 0     ║
+╟─────────┼───────╢
+║ Number  │ 7     ║
+╟─────────┼───────╢
+║ Name    │ Harry ║
+╚═════════╧═══════╝";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected)
+    }
+}
+
+mod customer {
+    use rct::ToTable;
+
+    #[derive(ToTable)]
+    pub struct Customer {
+        pub name: &'static str,
+        pub email: &'static str,
+    }
+}
+
+mod flatten {
+    use super::customer::Customer;
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    struct Order {
+        id: u32,
+        #[table(flatten, prefix = "customer ")]
+        customer: Customer,
+        total: f32,
+    }
+
+    #[test]
+    fn struct_flatten_test() {
+        let orders = vec![Order {
+            id: 1,
+            customer: Customer {
+                name: "Harry",
+                email: "harry@hogwarts.uk",
+            },
+            total: 14.87,
+        }];
+
+        let table = orders.into_iter().to_table();
+
+        let expected = "
+╔════╤═══════════════╤═══════════════════╤═══════╗
+║ id │ customer name │ customer email    │ total ║
+╟────┼───────────────┼───────────────────┼───────╢
+║ 1  │ Harry         │ harry@hogwarts.uk │ 14.87 ║
+╚════╧═══════════════╧═══════════════════╧═══════╝";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected)
+    }
+}
+
+mod flatten_group {
+    use super::customer::Customer;
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    struct Order {
+        id: u32,
+        #[table(flatten, group = "Customer")]
+        customer: Customer,
+        total: f32,
+    }
+
+    #[test]
+    fn struct_flatten_group_test() {
+        let orders = vec![Order {
+            id: 1,
+            customer: Customer {
+                name: "Harry",
+                email: "harry@hogwarts.uk",
+            },
+            total: 14.87,
+        }];
+
+        let table = orders.into_iter().to_table();
 
-}
\ No newline at end of file
+        let expected = "
+╔════╤═══════════════════════════╤═══════╗
+║    │         Customer          │       ║
+╟────┼───────┬───────────────────┼───────╢
+║ id │ name  │ email             │ total ║
+╟────┼───────┼───────────────────┼───────╢
+║ 1  │ Harry │ harry@hogwarts.uk │ 14.87 ║
+╚════╧═══════╧═══════════════════╧═══════╝";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected)
+    }
+}
+
+mod nested {
+    use super::customer::Customer;
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    struct Order {
+        id: u32,
+        #[table(nested)]
+        customers: Vec<Customer>,
+    }
+
+    #[test]
+    fn struct_nested_test() {
+        let orders = vec![Order {
+            id: 1,
+            customers: vec![
+                Customer {
+                    name: "Harry",
+                    email: "harry@hogwarts.uk",
+                },
+                Customer {
+                    name: "Ron",
+                    email: "ron@hogwarts.uk",
+                },
+            ],
+        }];
+
+        let table = orders.into_iter().to_table();
+
+        let expected = "
+╔════╤═══════════════════════════════╗
+║ id │ customers                     ║
+╟────┼───────────────────────────────╢
+║ 1  │ ╔═══════╤═══════════════════╗ ║
+║    │ ║ name  │ email             ║ ║
+║    │ ╟───────┼───────────────────╢ ║
+║    │ ║ Harry │ harry@hogwarts.uk ║ ║
+║    │ ╟───────┼───────────────────╢ ║
+║    │ ║ Ron   │ ron@hogwarts.uk   ║ ║
+║    │ ╚═══════╧═══════════════════╝ ║
+╚════╧═══════════════════════════════╝";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected)
+    }
+}
+
+mod references {
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    struct Movie {
+        id: u32,
+        title: &'static str,
+    }
+
+    // a second derive in the same module
+    #[derive(ToTable)]
+    struct Actor {
+        name: &'static str,
+    }
+
+    #[test]
+    fn to_table_by_reference_test() {
+        let movies = vec![
+            Movie {
+                id: 1,
+                title: "Avenger",
+            },
+            Movie {
+                id: 2,
+                title: "Spider-man",
+            },
+        ];
+
+        let expected = "
+╔════╤════════════╗
+║ id │ title      ║
+╟────┼────────────╢
+║ 1  │ Avenger    ║
+╟────┼────────────╢
+║ 2  │ Spider-man ║
+╚════╧════════════╝";
+
+        let table = movies.iter().to_table();
+        assert_eq!("\n".to_string() + &table.to_string(), expected);
+
+        let table = movies[..].to_table();
+        assert_eq!("\n".to_string() + &table.to_string(), expected);
+
+        let table = movies.iter().collect::<Vec<&Movie>>().to_table();
+        assert_eq!("\n".to_string() + &table.to_string(), expected);
+
+        let table = movies.to_table();
+        assert_eq!("\n".to_string() + &table.to_string(), expected);
+    }
+
+    #[test]
+    fn headers_to_row_test() {
+        let actor = Actor { name: "Tom" };
+
+        assert_eq!(Actor::headers(), vec!["name"]);
+        assert_eq!(actor.to_row().cells[0].data, vec!["Tom"]);
+    }
+}
+
+mod borrowed {
+    use rct::{IntoTable, ToTable};
+    use std::marker::PhantomData;
+
+    struct NotDisplay;
+
+    #[derive(ToTable)]
+    struct Movie<'a, T, U, M> {
+        title: &'a str,
+        rating: Option<T>,
+        tags: &'a [U],
+        #[table(skip)]
+        marker: PhantomData<M>,
+    }
+
+    #[test]
+    fn borrowed_generic_test() {
+        let tags = ["action".to_string(), "marvel".to_string()];
+        let movies: Vec<Movie<f32, String, NotDisplay>> = vec![
+            Movie {
+                title: "Avenger",
+                rating: Some(4.5),
+                tags: &tags,
+                marker: PhantomData,
+            },
+            Movie {
+                title: "Spider-man",
+                rating: None,
+                tags: &[],
+                marker: PhantomData,
+            },
+        ];
+
+        let table = (&movies).to_table();
+
+        let expected = "
+╔════════════╤════════╤════════════════╗
+║ title      │ rating │ tags           ║
+╟────────────┼────────┼────────────────╢
+║ Avenger    │ 4.5    │ action, marvel ║
+╟────────────┼────────┼────────────────╢
+║ Spider-man │ -      │                ║
+╚════════════╧════════╧════════════════╝";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected);
+        // the movies are still there
+        assert_eq!(movies.len(), 2);
+    }
+}
+
+mod presentation {
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    #[table(border = "simple", title = "Movies")]
+    struct Movie {
+        #[table(rename = "Title", width = 10)]
+        title: &'static str,
+        #[table(rename = "Description", max_width = 12, truncate)]
+        description: &'static str,
+        #[table(rename = "ID", order = 0)]
+        id: u32,
+        #[table(rename = "Price", align = "right")]
+        price: f32,
+    }
+
+    #[test]
+    fn struct_presentation_test() {
+        let movies = [
+            Movie {
+                title: "Avenger",
+                description: "Earth's mightiest heroes",
+                id: 1,
+                price: 18.5,
+            },
+            Movie {
+                title: "Spider-man, No Way Home",
+                description: "Multiverse",
+                id: 2,
+                price: 8.0,
+            },
+        ];
+
+        let table = movies.iter().to_table();
+
+        let expected = "
++----------------------------------------+
+|                 Movies                 |
++----+------------+--------------+-------+
+| ID │ Title      │ Description  │ Price |
++----+------------+--------------+-------+
+| 1  │ Avenger    │ Earth's mig… │  18.5 |
++----+------------+--------------+-------+
+| 2  │ Spider-man │ Multiverse   │     8 |
+|    │ , No Way   │              │       |
+|    │ Home       │              │       |
++----+------------+--------------+-------+";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected)
+    }
+}
+
+mod style_with {
+    use rct::styles::color::{Colorizer, Font, Style};
+    use rct::{ICell, ToTable};
+
+    fn status_style(status: &str) -> Style {
+        match status {
+            "failed" => Style::new().color("#ff0000"),
+            _ => Style::new().color("#00ff00"),
+        }
+    }
+
+    fn highlight(task: &Task) -> Style {
+        match task.retries > 2 {
+            true => Style::new().font(Font::Bold),
+            false => Style::new(),
+        }
+    }
+
+    #[derive(ToTable)]
+    #[table(row_style_with = "highlight")]
+    struct Task {
+        name: String,
+        #[table(style_with = "status_style")]
+        status: String,
+        retries: u32,
+    }
+
+    #[test]
+    fn struct_style_with_test() {
+        let done = Task {
+            name: "build".to_string(),
+            status: "done".to_string(),
+            retries: 0,
+        };
+        let failed = Task {
+            name: "deploy".to_string(),
+            status: "failed".to_string(),
+            retries: 3,
+        };
+
+        let row = done.to_row();
+        assert_eq!(row.cells[0].to_string(), "build");
+        assert_eq!(
+            row.cells[1].to_string(),
+            "done".cell().color("#00ff00").to_string()
+        );
+
+        let row = failed.to_row();
+        assert_eq!(
+            row.cells[0].to_string(),
+            "deploy".cell().font(Font::Bold).to_string()
+        );
+        assert_eq!(
+            row.cells[1].to_string(),
+            "failed"
+                .cell()
+                .color("#ff0000")
+                .font(Font::Bold)
+                .to_string()
+        );
+        assert_eq!(row.cells[2].width, 1);
+    }
+}
+
+mod expanded {
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    #[table(layout = "expanded", rename_all = "Title Case")]
+    struct Server {
+        host_name: &'static str,
+        cpu_count: u32,
+    }
+
+    #[test]
+    fn struct_expanded_test() {
+        let servers = [
+            Server {
+                host_name: "db-1",
+                cpu_count: 16,
+            },
+            Server {
+                host_name: "web-1",
+                cpu_count: 4,
+            },
+        ];
+
+        let table = servers.iter().to_table();
+
+        let expected = "
+╔═[ RECORD 1 ]══════╗
+║ Host Name │ db-1  ║
+║ Cpu Count │ 16    ║
+╟─[ RECORD 2 ]──────╢
+║ Host Name │ web-1 ║
+║ Cpu Count │ 4     ║
+╚═══════════╧═══════╝";
+
+        assert_eq!("\n".to_string() + &table.to_string(), expected)
+    }
+}
+
+mod typed {
+    use rct::value::Value;
+    use rct::ToTable;
+
+    fn euros(price: &f64) -> String {
+        format!("{:.2} €", price)
+    }
+
+    #[derive(ToTable)]
+    struct Book {
+        title: String,
+        #[table(display_with = "euros")]
+        price: f64,
+        stock: Option<u32>,
+        available: bool,
+    }
+
+    #[test]
+    fn struct_typed_values_test() {
+        let book = Book {
+            title: "Harry Potter".to_string(),
+            price: 14.5,
+            stock: None,
+            available: true,
+        };
+
+        let row = book.to_row();
+        assert_eq!(row.cells[0].value(), None);
+        assert_eq!(row.cells[1].to_string(), "14.50 €");
+        assert_eq!(row.cells[1].value(), Some(&Value::Float(14.5)));
+        assert_eq!(row.cells[2].to_string(), "-");
+        assert_eq!(row.cells[2].value(), Some(&Value::Null));
+        assert_eq!(row.cells[3].to_string(), "✓");
+        assert_eq!(row.cells[3].value(), Some(&Value::Bool(true)));
+    }
+}
+
+mod number_format {
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    struct Download {
+        name: &'static str,
+        #[table(bytes)]
+        size: u64,
+        #[table(percent, decimals = 0)]
+        progress: f64,
+        #[table(currency_after = "€", thousands = " ")]
+        price: f64,
+    }
+
+    #[test]
+    fn struct_number_format_test() {
+        let downloads = [
+            Download {
+                name: "rust.tar.gz",
+                size: 254_803_968,
+                progress: 0.5,
+                price: 1250.0,
+            },
+            Download {
+                name: "notes.txt",
+                size: 512,
+                progress: 1.0,
+                price: 0.5,
+            },
+        ];
+
+        let table = downloads.iter().to_table();
+
+        let expected = "
+╔═════════════╤═══════════╤══════════╤════════════╗
+║ name        │ size      │ progress │ price      ║
+╟─────────────┼───────────┼──────────┼────────────╢
+║ rust.tar.gz │ 243.0 MiB │ 50%      │ 1 250.00 € ║
+╟─────────────┼───────────┼──────────┼────────────╢
+║ notes.txt   │ 512 B     │ 100%     │ 0.50 €     ║
+╚═════════════╧═══════════╧══════════╧════════════╝";
+        assert_eq!("\n".to_string() + &table.to_string(), expected);
+        assert_eq!(
+            table.to_csv(),
+            "name,size,progress,price\nrust.tar.gz,254803968,0.5,1250\nnotes.txt,512,1,0.5\n"
+        );
+    }
+}
+
+mod frozen {
+    use rct::{IntoTable, ToTable};
+
+    #[derive(ToTable)]
+    #[table(border = "simple")]
+    struct Server {
+        #[table(freeze)]
+        id: u32,
+        host_name: &'static str,
+        region: &'static str,
+    }
+
+    #[test]
+    fn struct_frozen_test() {
+        let servers = [
+            Server {
+                id: 1,
+                host_name: "db-1.example.com",
+                region: "eu-west-3",
+            },
+            Server {
+                id: 2,
+                host_name: "web-1.example.com",
+                region: "us-east-1",
+            },
+        ];
+
+        let chunks = servers.iter().to_table().split_columns(30);
+
+        let expected = "
++----+-----------+
+| id │ region    |
++----+-----------+
+| 1  │ eu-west-3 |
++----+-----------+
+| 2  │ us-east-1 |
++----+-----------+";
+        assert_eq!(chunks.len(), 2);
+        assert_eq!("\n".to_string() + &chunks[1].to_string(), expected);
+    }
+}
@@ -1,9 +1,15 @@
 #[test]
 fn ui() {
     let t = trybuild::TestCases::new();
-    t.compile_fail("tests/ui/enum.rs");
+    t.pass("tests/ui/enum.rs");
     t.compile_fail("tests/ui/union.rs");
     t.pass("tests/ui/struct_named.rs");
-    t.compile_fail("tests/ui/struct_unnamed.rs");
+    t.pass("tests/ui/struct_unnamed.rs");
     t.compile_fail("tests/ui/struct_unit.rs");
+    t.compile_fail("tests/ui/struct_tag.rs");
+    t.compile_fail("tests/ui/rename_all_invalid.rs");
+    t.compile_fail("tests/ui/attribute_unknown.rs");
+    t.compile_fail("tests/ui/display_with_format.rs");
+    t.compile_fail("tests/ui/flatten_enum.rs");
+    t.compile_fail("tests/ui/number_units.rs");
 }
@@ -0,0 +1,9 @@
+use rct::ToTable;
+
+#[derive(ToTable)]
+struct Tests {
+    #[table(name = "ID")]
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use rct::ToTable;
+
+fn price(value: &f32) -> String {
+    format!("{value:.2}")
+}
+
+#[derive(ToTable)]
+struct Tests {
+    #[table(display_with = "price", format = "{:.2}")]
+    price: f32,
+}
+
+fn main() {}
@@ -3,7 +3,8 @@ use rct::ToTable;
 #[derive(ToTable)]
 enum Tests {
     Value1,
-    Value2,
+    Value2 { price: f32 },
+    Value3(String, f32),
 }
 
-fn main () {}
\ No newline at end of file
+fn main() {}
@@ -0,0 +1,20 @@
+use rct::ToTable;
+
+mod inner {
+    use rct::ToTable;
+
+    #[derive(ToTable)]
+    pub struct Inner {
+        pub price: f32,
+    }
+}
+
+#[derive(ToTable)]
+enum Tests {
+    Value1 {
+        #[table(flatten)]
+        inner: inner::Inner,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rct::ToTable;
+
+#[derive(ToTable)]
+struct Tests {
+    #[table(currency = "$", percent)]
+    price: f32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use rct::ToTable;
+
+#[derive(ToTable)]
+#[table(rename_all = "Header Case")]
+struct Tests {
+    id: u32,
+}
+
+fn main() {}
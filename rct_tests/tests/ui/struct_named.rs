@@ -1,4 +1,4 @@
-use rct::styles::color::{Colorizer, Font};
+use rct::styles::color::Font;
 use rct::ToTable;
 
 #[derive(ToTable)]
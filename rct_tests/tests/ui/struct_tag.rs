@@ -0,0 +1,9 @@
+use rct::ToTable;
+
+#[derive(ToTable)]
+#[table(tag = "Kind")]
+struct Tests {
+    price: f32,
+}
+
+fn main() {}